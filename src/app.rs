@@ -2,19 +2,35 @@ use std::sync::{Arc, Mutex};
 
 use iced::widget::column;
 use iced::{Element, Task};
+use iced_aw::date_picker::Date;
+use uuid::Uuid;
 
-use crate::components::navigation_bar::{view_admin, view_user};
+use crate::components::navigation_bar::{view_admin, view_user, HistoryState};
 use crate::components::notification::{Notification, NotificationMessage, NotificationType};
 use crate::components::validator::Validator;
+use crate::config::AppConfig;
+use crate::constants::{MAX_HISTORY_LENGTH, MAX_RECENT_GUESTS};
+use crate::model::{booking::Booking, guest::Guest, room::Room};
 use crate::screens::add_guest::{AddGuestMessage, AddGuestScreen};
 use crate::screens::add_room::{AddRoomMessage, AddRoomScreen};
 use crate::screens::book_room::{BookRoomMessage, BookRoomScreen};
-use crate::screens::home::HomeScreen;
+use crate::screens::booking_details::{BookingDetailsMessage, BookingDetailsScreen};
+use crate::screens::bookings::{BookingsMessage, BookingsScreen};
+use crate::screens::expiring_documents::{ExpiringDocumentsMessage, ExpiringDocumentsScreen};
+use crate::screens::guests::{GuestsMessage, GuestsScreen};
+use crate::screens::home::{HomeMessage, HomeScreen};
 use crate::screens::login::{LoginMessage, LoginScreen};
+use crate::screens::my_account::{MyAccountMessage, MyAccountScreen};
+use crate::screens::my_bookings::{MyBookingsMessage, MyBookingsScreen};
+use crate::screens::occupancy_calendar::{OccupancyCalendarMessage, OccupancyCalendarScreen};
 use crate::screens::register::{RegisterMessage, RegisterScreen};
 use crate::screens::reset_password::{ResetPasswordMessage, ResetPasswordScreen};
-use crate::security::{JwtToken, Role};
-use crate::services;
+use crate::screens::rooms::{RoomsMessage, RoomsScreen};
+use crate::screens::search_availability::{SearchAvailabilityMessage, SearchAvailabilityScreen};
+use crate::screens::today::{TodayMessage, TodayScreen};
+use crate::security::{JwtToken, JwtVerifier, Role};
+use crate::services::{self, api_client::ApiClient, api_client::ApiResult};
+use crate::session_store::{self, StoredSession};
 use crate::utils::show_notification;
 
 #[derive(Debug, Default)]
@@ -22,6 +38,43 @@ pub struct GlobalState {
     pub token: Option<JwtToken>,
     pub validator: Validator,
     pub email: Option<String>,
+    pub config: AppConfig,
+    pub http_client: reqwest::Client,
+    pub remember_session: bool,
+    pub jwks_verifier: Option<JwtVerifier>,
+    pub recent_guests: Vec<Uuid>,
+}
+impl GlobalState {
+    pub fn base_url(&self) -> String {
+        self.config.base_url.clone()
+    }
+
+    pub fn set_token(&mut self, token: JwtToken) {
+        if self.remember_session {
+            let session = StoredSession {
+                email: self.email.clone().unwrap_or_default(),
+                token: token.token_string.clone(),
+            };
+            if let Err(err) = session_store::save_session(&session) {
+                println!("Error storing session: {err}");
+            }
+        }
+        self.token = Some(token);
+    }
+
+    pub fn add_recent_guest(&mut self, guest_id: Uuid) {
+        self.recent_guests.retain(|id| *id != guest_id);
+        self.recent_guests.insert(0, guest_id);
+        self.recent_guests.truncate(MAX_RECENT_GUESTS);
+    }
+
+    pub fn clear_token(&mut self) {
+        self.token = None;
+        self.remember_session = false;
+        if let Err(err) = session_store::clear_session() {
+            println!("Error clearing stored session: {err}");
+        }
+    }
 }
 
 pub trait Screen {
@@ -31,6 +84,12 @@ pub trait Screen {
         global_state: Arc<Mutex<GlobalState>>,
     ) -> Task<AppMessage>;
     fn view(&self, global_state: Arc<Mutex<GlobalState>>) -> Element<AppMessage>;
+    fn keep_alive(&self) -> bool {
+        false
+    }
+    fn on_open(&mut self, _global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        Task::none()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -39,16 +98,31 @@ pub enum AppMessage {
     SelectNext,
     SelectPrev,
     RefreshToken,
+    RefreshTokenFinished(bool),
     Logout,
+    SessionExpiring,
     TokenExpired,
     NotificationMessage(NotificationMessage),
     NavigateTo(ScreenType),
+    NavigateBack,
+    NavigateForward,
     LoginMessage(LoginMessage),
     AddRoomMessage(AddRoomMessage),
     AddGuestMessage(AddGuestMessage),
     RegisterMessage(RegisterMessage),
     ResetPasswordMessage(ResetPasswordMessage),
     BookRoomMessage(BookRoomMessage),
+    HomeMessage(HomeMessage),
+    MyBookingsMessage(MyBookingsMessage),
+    SearchAvailabilityMessage(SearchAvailabilityMessage),
+    MyAccountMessage(MyAccountMessage),
+    RoomsMessage(RoomsMessage),
+    GuestsMessage(GuestsMessage),
+    ExpiringDocumentsMessage(ExpiringDocumentsMessage),
+    BookingsMessage(BookingsMessage),
+    BookingDetailsMessage(BookingDetailsMessage),
+    TodayMessage(TodayMessage),
+    OccupancyCalendarMessage(OccupancyCalendarMessage),
 }
 
 #[derive(Debug, Clone)]
@@ -60,8 +134,51 @@ pub enum ScreenType {
     Register,
     ResetPassword,
     BookRoom,
+    MyBookings,
+    SearchAvailability,
+    MyAccount,
+    Rooms,
+    EditRoom(Box<Room>),
+    Guests,
+    EditGuest(Box<Guest>),
+    ExpiringDocuments,
+    Bookings,
+    BookingDetails(Box<Booking>),
+    Today,
+    OccupancyCalendar,
+    BookRoomFor(Uuid, Date),
 }
 impl ScreenType {
+    pub fn is_allowed_for(&self, role: Option<Role>) -> bool {
+        match self.allowed_roles() {
+            Some(allowed_roles) => role.is_some_and(|role| allowed_roles.contains(&role)),
+            None => true,
+        }
+    }
+
+    fn allowed_roles(&self) -> Option<&'static [Role]> {
+        match self {
+            ScreenType::Login | ScreenType::Register | ScreenType::ResetPassword => None,
+            ScreenType::Home
+            | ScreenType::MyBookings
+            | ScreenType::SearchAvailability
+            | ScreenType::MyAccount => Some(&[Role::User, Role::Admin]),
+            ScreenType::AddRoom
+            | ScreenType::AddGuest
+            | ScreenType::BookRoom
+            | ScreenType::Rooms
+            | ScreenType::EditRoom(_)
+            | ScreenType::Guests
+            | ScreenType::EditGuest(_)
+            | ScreenType::ExpiringDocuments
+            | ScreenType::Bookings
+            | ScreenType::BookingDetails(_)
+            | ScreenType::Today
+            | ScreenType::OccupancyCalendar
+            | ScreenType::BookRoomFor(_, _) => Some(&[Role::Admin]),
+        }
+    }
+
     fn create_screen(&self) -> Box<dyn Screen> {
         match self {
             ScreenType::Home => Box::new(HomeScreen::new()),
@@ -71,29 +188,165 @@ impl ScreenType {
             ScreenType::Register => Box::new(RegisterScreen::new()),
             ScreenType::ResetPassword => Box::new(ResetPasswordScreen::new()),
             ScreenType::BookRoom => Box::new(BookRoomScreen::new()),
+            ScreenType::MyBookings => Box::new(MyBookingsScreen::new()),
+            ScreenType::SearchAvailability => Box::new(SearchAvailabilityScreen::new()),
+            ScreenType::MyAccount => Box::new(MyAccountScreen::new()),
+            ScreenType::Rooms => Box::new(RoomsScreen::new()),
+            ScreenType::EditRoom(room) => Box::new(AddRoomScreen::edit(room)),
+            ScreenType::Guests => Box::new(GuestsScreen::new()),
+            ScreenType::EditGuest(guest) => Box::new(AddGuestScreen::edit(guest)),
+            ScreenType::ExpiringDocuments => Box::new(ExpiringDocumentsScreen::new()),
+            ScreenType::Bookings => Box::new(BookingsScreen::new()),
+            ScreenType::BookingDetails(booking) => Box::new(BookingDetailsScreen::new(booking)),
+            ScreenType::Today => Box::new(TodayScreen::new()),
+            ScreenType::OccupancyCalendar => Box::new(OccupancyCalendarScreen::new()),
+            ScreenType::BookRoomFor(room_id, date) => {
+                Box::new(BookRoomScreen::prefilled(*room_id, *date))
+            }
         }
     }
 }
 
+struct HistoryEntry {
+    screen_type: ScreenType,
+    screen: Option<Box<dyn Screen>>,
+}
+
 pub struct HotelApp {
     current_screen: Box<dyn Screen>,
     screen_type: ScreenType,
     notification: Notification,
     global_state: Arc<Mutex<GlobalState>>,
+    back_stack: Vec<HistoryEntry>,
+    forward_stack: Vec<HistoryEntry>,
+    refreshing_token: bool,
+    expiry_warning_deferred: bool,
 }
 impl HotelApp {
+    fn replace_current(
+        &mut self,
+        screen_type: ScreenType,
+        screen: Box<dyn Screen>,
+    ) -> HistoryEntry {
+        let previous_type = std::mem::replace(&mut self.screen_type, screen_type);
+        let previous_screen = std::mem::replace(&mut self.current_screen, screen);
+
+        HistoryEntry {
+            screen: previous_screen.keep_alive().then_some(previous_screen),
+            screen_type: previous_type,
+        }
+    }
+
     fn navigate_to(&mut self, screen: &ScreenType) -> Task<AppMessage> {
         println!("Navigating to: {screen:?}");
-        self.screen_type = screen.clone();
-        self.current_screen = screen.create_screen();
-        Task::none()
+        let crosses_login =
+            screen.allowed_roles().is_some() != self.screen_type.allowed_roles().is_some();
+
+        let previous = self.replace_current(screen.clone(), screen.create_screen());
+        self.forward_stack.clear();
+        if crosses_login {
+            self.back_stack.clear();
+        } else {
+            self.back_stack.push(previous);
+            if self.back_stack.len() > MAX_HISTORY_LENGTH {
+                self.back_stack.remove(0);
+            }
+        }
+        self.current_screen.on_open(self.global_state.clone())
     }
 
-    pub fn new() -> (Self, Task<AppMessage>) {
-        let global_state = Arc::new(Mutex::new(GlobalState::default()));
+    fn restore_entry(&mut self, entry: HistoryEntry) -> (HistoryEntry, Task<AppMessage>) {
+        println!("Restoring: {:?}", entry.screen_type);
+        let screen = entry
+            .screen
+            .unwrap_or_else(|| entry.screen_type.create_screen());
+        let previous = self.replace_current(entry.screen_type, screen);
+
+        (
+            previous,
+            self.current_screen.on_open(self.global_state.clone()),
+        )
+    }
+
+    fn navigate_back(&mut self) -> Task<AppMessage> {
+        let Some(entry) = self.back_stack.pop() else {
+            return Task::none();
+        };
+        if !self.is_permitted(&entry.screen_type) {
+            self.back_stack.clear();
+            return Task::none();
+        }
+
+        let (current, task) = self.restore_entry(entry);
+        self.forward_stack.push(current);
+        task
+    }
+
+    fn navigate_forward(&mut self) -> Task<AppMessage> {
+        let Some(entry) = self.forward_stack.pop() else {
+            return Task::none();
+        };
+        if !self.is_permitted(&entry.screen_type) {
+            self.forward_stack.clear();
+            return Task::none();
+        }
+
+        let (current, task) = self.restore_entry(entry);
+        self.back_stack.push(current);
+        task
+    }
+
+    fn current_role(&self) -> Option<Role> {
+        self.global_state
+            .lock()
+            .unwrap()
+            .token
+            .as_ref()
+            .map(|token| token.role)
+    }
+
+    fn is_permitted(&self, screen: &ScreenType) -> bool {
+        screen.is_allowed_for(self.current_role())
+    }
+
+    fn guarded_navigate_to(&mut self, screen: &ScreenType) -> Task<AppMessage> {
+        let Some(allowed_roles) = screen.allowed_roles() else {
+            return self.navigate_to(screen);
+        };
+
+        match self.current_role() {
+            Some(role) if allowed_roles.contains(&role) => self.navigate_to(screen),
+            Some(role) => {
+                println!("Navigation to {screen:?} not permitted for {role:?}");
+                Task::done(show_notification("Not permitted", NotificationType::Error))
+            }
+            None => {
+                println!("Navigation to {screen:?} requires login");
+                Task::done(show_notification(
+                    "Log in to continue",
+                    NotificationType::Information,
+                ))
+                .chain(Task::done(AppMessage::NavigateTo(ScreenType::Login)))
+            }
+        }
+    }
+
+    pub fn new(config: AppConfig) -> (Self, Task<AppMessage>) {
+        let global_state = Arc::new(Mutex::new(GlobalState {
+            config,
+            ..Default::default()
+        }));
         let screen_type = ScreenType::Login;
         let current_screen = screen_type.create_screen();
         let notification = Notification::new();
+        let task = match session_store::load_session() {
+            Ok(Some(session)) => Self::restore_session(&global_state, session),
+            Ok(None) => Task::none(),
+            Err(err) => {
+                println!("Error loading stored session: {err}");
+                Task::none()
+            }
+        };
 
         (
             Self {
@@ -101,29 +354,93 @@ impl HotelApp {
                 screen_type,
                 global_state,
                 notification,
+                back_stack: Vec::new(),
+                forward_stack: Vec::new(),
+                refreshing_token: false,
+                expiry_warning_deferred: false,
             },
-            Task::none(),
+            task,
         )
     }
 
+    fn restore_session(
+        global_state: &Arc<Mutex<GlobalState>>,
+        session: StoredSession,
+    ) -> Task<AppMessage> {
+        // The stored token has usually expired, it is only used to request a fresh one,
+        // which is verified on arrival
+        let Ok(token) = JwtToken::new_unvalidated(session.token) else {
+            global_state.lock().unwrap().clear_token();
+            return Task::none();
+        };
+        {
+            let mut lock = global_state.lock().unwrap();
+            lock.email = Some(session.email);
+            lock.remember_session = true;
+            lock.token = Some(token);
+        }
+        let client = ApiClient::new(global_state);
+        // Kept out of the state until refreshed, the expiry subscription would end the session
+        global_state.lock().unwrap().token = None;
+
+        let global_state = global_state.clone();
+        Task::perform(services::refresh_token::refresh_token(client), move |res| {
+            let mut lock = global_state.lock().unwrap();
+            match res {
+                ApiResult::Success(token) => {
+                    println!("Restored stored session");
+                    lock.set_token(token);
+                    AppMessage::NavigateTo(ScreenType::Home)
+                }
+                ApiResult::Forbidden => {
+                    lock.clear_token();
+                    show_notification(
+                        "Stored session expired, log in again",
+                        NotificationType::Information,
+                    )
+                }
+                ApiResult::BadRequest(err) | ApiResult::Error(err) => {
+                    println!("Error restoring session: {err}");
+                    lock.token = None;
+                    show_notification("Could not restore session", NotificationType::Error)
+                }
+            }
+        })
+    }
+
+    pub fn session_expiry(&self) -> Option<(u64, u64)> {
+        self.global_state
+            .lock()
+            .unwrap()
+            .token
+            .as_ref()
+            .map(|token| (token.iat, token.exp))
+    }
+
+    pub fn screen_type(&self) -> &ScreenType {
+        &self.screen_type
+    }
+
     pub fn title(&self) -> String {
         "Hotel".to_owned()
     }
 
     fn logout(&mut self) -> Task<AppMessage> {
-        let mut lock = self.global_state.lock().unwrap();
-        let token = lock.token.as_ref().map(|jwt| jwt.token_string.clone());
+        let client = ApiClient::new(&self.global_state);
+        self.global_state.lock().unwrap().clear_token();
 
-        lock.token = None;
-        drop(lock);
         Task::done(AppMessage::NavigateTo(ScreenType::Login)).chain(Task::perform(
-            services::logout::logout(token),
+            services::logout::logout(client),
             move |res| match res {
-                Ok(_) => {
+                ApiResult::Success(_) => {
                     println!("Logged out");
                     AppMessage::None
                 }
-                Err(err) => {
+                ApiResult::Forbidden => {
+                    println!("Error loging out: session already expired");
+                    AppMessage::None
+                }
+                ApiResult::BadRequest(err) | ApiResult::Error(err) => {
                     println!("Error loging out: {err}");
                     AppMessage::None
                 }
@@ -131,15 +448,25 @@ impl HotelApp {
         ))
     }
 
+    fn token_expired(&mut self) -> Task<AppMessage> {
+        self.global_state.lock().unwrap().clear_token();
+
+        Task::done(show_notification(
+            "Login session expired",
+            NotificationType::Error,
+        ))
+        .chain(Task::done(AppMessage::NavigateTo(ScreenType::Login)))
+    }
+
     pub fn update(&mut self, message: AppMessage) -> Task<AppMessage> {
         match message {
-            AppMessage::NavigateTo(screen_type) => self.navigate_to(&screen_type),
-            AppMessage::TokenExpired => Task::done(show_notification(
-                "Login session expired",
-                NotificationType::Error,
-            ))
-            .chain(Task::done(AppMessage::NavigateTo(ScreenType::Login))),
+            AppMessage::NavigateTo(screen_type) => self.guarded_navigate_to(&screen_type),
+            AppMessage::NavigateBack => self.navigate_back(),
+            AppMessage::NavigateForward => self.navigate_forward(),
+            AppMessage::TokenExpired => self.token_expired(),
+            AppMessage::SessionExpiring => self.session_expiring(),
             AppMessage::RefreshToken => self.refresh_token(),
+            AppMessage::RefreshTokenFinished(success) => self.refresh_token_finished(success),
             AppMessage::None => Task::none(),
             AppMessage::NotificationMessage(notification_message) => {
                 self.notification.update(notification_message)
@@ -152,15 +479,19 @@ impl HotelApp {
     }
 
     pub fn view(&self) -> Element<AppMessage> {
+        let history = HistoryState {
+            can_go_back: !self.back_stack.is_empty(),
+            can_go_forward: !self.forward_stack.is_empty(),
+        };
+
         column![match self.screen_type {
             ScreenType::Login | ScreenType::Register | ScreenType::ResetPassword =>
                 self.current_screen.view(self.global_state.clone()),
-            _ => match &self.global_state.lock().unwrap().token {
-                Some(some) => match some.role {
-                    Role::User => view_user(self.global_state.clone(), &*self.current_screen),
-                    Role::Admin => view_admin(self.global_state.clone(), &*self.current_screen),
-                },
-                None => view_user(self.global_state.clone(), &*self.current_screen),
+            _ => match self.current_role() {
+                Some(Role::Admin) =>
+                    view_admin(self.global_state.clone(), &*self.current_screen, history),
+                Some(Role::User) | None =>
+                    view_user(self.global_state.clone(), &*self.current_screen, history),
             },
         }]
         .push_maybe(self.notification.view())
@@ -168,29 +499,48 @@ impl HotelApp {
         .into()
     }
 
-    fn refresh_token(&self) -> Task<AppMessage> {
+    fn session_expiring(&mut self) -> Task<AppMessage> {
+        if self.refreshing_token {
+            self.expiry_warning_deferred = true;
+            return Task::none();
+        }
+
+        Task::done(show_notification(
+            "Login session is about to expire",
+            NotificationType::Information,
+        ))
+    }
+
+    fn refresh_token_finished(&mut self, success: bool) -> Task<AppMessage> {
+        self.refreshing_token = false;
+        if std::mem::take(&mut self.expiry_warning_deferred) && !success {
+            return self.session_expiring();
+        }
+        Task::none()
+    }
+
+    fn refresh_token(&mut self) -> Task<AppMessage> {
         if self.global_state.lock().unwrap().token.is_some() {
+            self.refreshing_token = true;
             let global_state_copy = self.global_state.clone();
-            let token_string = global_state_copy
-                .lock()
-                .unwrap()
-                .token
-                .as_ref()
-                .unwrap()
-                .token_string
-                .clone();
+            let client = ApiClient::new(&self.global_state);
 
             Task::perform(
-                async { services::refresh_token::refresh_token(token_string).await },
-                move |res| {
-                    match res {
-                        Ok(ok) => {
-                            println!("refreshed token: {}", ok.token_string);
-                            global_state_copy.lock().unwrap().token = Some(ok);
-                        }
-                        Err(err) => println!("Error refreshing token: {err}"),
+                services::refresh_token::refresh_token(client),
+                move |res| match res {
+                    ApiResult::Success(ok) => {
+                        println!("refreshed token: {}", ok.token_string);
+                        global_state_copy.lock().unwrap().set_token(ok);
+                        AppMessage::RefreshTokenFinished(true)
+                    }
+                    ApiResult::Forbidden => {
+                        println!("Error refreshing token: forbidden");
+                        AppMessage::RefreshTokenFinished(false)
+                    }
+                    ApiResult::BadRequest(err) | ApiResult::Error(err) => {
+                        println!("Error refreshing token: {err}");
+                        AppMessage::RefreshTokenFinished(false)
                     }
-                    AppMessage::None
                 },
             )
         } else {
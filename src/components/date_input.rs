@@ -1,4 +1,5 @@
 use crate::app::AppMessage;
+use crate::styles::ERROR_COLOR;
 use iced::advanced::graphics::core::Element;
 use iced::widget::button;
 use iced::{Renderer, Theme};
@@ -46,14 +47,34 @@ impl DateInput {
     where
         F: 'static + Fn(Date) -> AppMessage,
     {
+        self.view_highlighted(on_submit, false)
+    }
+
+    pub fn view_highlighted<F>(
+        &self,
+        on_submit: F,
+        highlight: bool,
+    ) -> Element<'_, AppMessage, Theme, Renderer>
+    where
+        F: 'static + Fn(Date) -> AppMessage,
+    {
+        let underlay = button(self.display_text.as_ref())
+            .on_press(self.on_cancel.clone())
+            .style(move |theme, status| {
+                let mut style = button::primary(theme, status);
+                if highlight {
+                    style.border.color = ERROR_COLOR;
+                    style.border.width = 2.0;
+                }
+                style
+            });
+
         date_picker(
             self.show_picker,
             self.date,
             <iced::widget::Button<'_, AppMessage, Theme, Renderer> as Into<
                 Element<'_, AppMessage, Theme, iced::Renderer>,
-            >>::into(
-                button(self.display_text.as_ref()).on_press(self.on_cancel.clone())
-            ),
+            >>::into(underlay),
             self.on_cancel.clone(),
             on_submit,
         )
@@ -8,7 +8,6 @@ pub struct FocusChain {
 }
 impl FocusChain {
     pub fn new(chain: Vec<&'static str>) -> Self {
-        assert!(!chain.is_empty());
         Self {
             chain,
             selected: None,
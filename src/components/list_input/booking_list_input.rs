@@ -0,0 +1,154 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use iced::{
+    border::Radius,
+    widget::{column, container::Style, scrollable::Viewport, text},
+    Border, Element, Task, Theme,
+};
+use uuid::Uuid;
+
+use crate::{
+    app::{AppMessage, GlobalState},
+    model::booking::Booking,
+    services::{
+        api_client::{ApiClient, ApiResult},
+        get_booking::get_booking,
+    },
+};
+
+pub struct BookingListInput {
+    ids: Vec<Uuid>,
+    loaded: HashMap<Uuid, Booking>,
+}
+impl BookingListInput {
+    pub fn new() -> Self {
+        const INITIAL_CAPACITY: usize = 100;
+        Self {
+            ids: Vec::with_capacity(INITIAL_CAPACITY),
+            loaded: HashMap::with_capacity(INITIAL_CAPACITY),
+        }
+    }
+
+    fn get_booking_container_style(theme: &Theme) -> Style {
+        let color = theme.palette().primary;
+
+        Style {
+            border: Border {
+                color,
+                width: 2.0,
+                radius: Radius::new(4),
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn view<'a, F, S>(&'a self, view_booking: F, on_scroll: S) -> Element<'a, AppMessage>
+    where
+        F: Fn(&'a Booking) -> Element<'a, AppMessage>,
+        S: Fn(Viewport) -> AppMessage + 'static,
+    {
+        const WIDTH: u16 = 560;
+        const HEIGHT: u16 = 70;
+        if self.ids.is_empty() {
+            return column![].into();
+        }
+
+        let mut booking_views = column![];
+        for id in &self.ids {
+            let element = match self.loaded.get(id) {
+                Some(booking) => view_booking(booking),
+                None => text!("Loading ...").center().size(18).into(),
+            };
+            booking_views = booking_views.push(
+                iced::widget::container(element)
+                    .width(WIDTH)
+                    .height(HEIGHT)
+                    .style(Self::get_booking_container_style)
+                    .padding(5),
+            );
+        }
+
+        iced::widget::scrollable(booking_views.spacing(5))
+            .height(400)
+            .spacing(10)
+            .on_scroll(on_scroll)
+            .into()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn update_ids<F>(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        booking_ids: Vec<Uuid>,
+        map_result: F,
+    ) -> Task<AppMessage>
+    where
+        F: Fn(ApiResult<Booking>) -> AppMessage + Clone + Send + Sync + 'static,
+    {
+        self.ids = booking_ids;
+        // Bookings change status, so reload them on every search
+        self.loaded.clear();
+        let client = ApiClient::new(&global_state);
+
+        const INITIAL_LOAD: usize = 8;
+
+        self.load_elements(client, INITIAL_LOAD, map_result)
+    }
+
+    pub fn load_scrolled<F>(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        scroll_percent: f32,
+        map_result: F,
+    ) -> Task<AppMessage>
+    where
+        F: Fn(ApiResult<Booking>) -> AppMessage + Clone + Send + Sync + 'static,
+    {
+        let client = ApiClient::new(&global_state);
+
+        let number_to_load = (scroll_percent * self.ids.len() as f32) as usize;
+
+        self.load_elements(client, number_to_load, map_result)
+    }
+
+    pub fn update_loaded(&mut self, booking: Booking) {
+        self.loaded.insert(booking.id, booking);
+    }
+
+    fn load_elements<F>(
+        &mut self,
+        client: ApiClient,
+        number: usize,
+        map_result: F,
+    ) -> Task<AppMessage>
+    where
+        F: Fn(ApiResult<Booking>) -> AppMessage + Clone + Send + Sync + 'static,
+    {
+        const EXTRA_TO_LOAD: usize = 8;
+        let elements_to_load = (number + EXTRA_TO_LOAD).clamp(0, self.ids.len());
+        let to_load: Vec<_> = self
+            .ids
+            .iter()
+            .take(elements_to_load)
+            .filter(|uuid| !self.loaded.contains_key(uuid))
+            .copied()
+            .collect();
+
+        let tasks: Vec<_> = to_load
+            .into_iter()
+            .map(|id| Task::perform(get_booking(client.clone(), id), map_result.clone()))
+            .collect();
+
+        Task::batch(tasks)
+    }
+
+    pub fn get_loaded(&self, id: Uuid) -> Option<&Booking> {
+        self.loaded.get(&id)
+    }
+}
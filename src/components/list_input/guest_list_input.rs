@@ -9,11 +9,16 @@ use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
 use iced::{widget::scrollable::Viewport, Task};
+use iced_aw::date_picker::Date;
 
-use crate::model::guest::Guest;
+use crate::model::{guest::Guest, phone_number::format_phone_number};
 use crate::{
     app::{AppMessage, GlobalState},
-    services::get_guest::{get_guest, GetGuestResult},
+    services::{
+        api_client::{ApiClient, ApiResult},
+        get_guest::get_guest,
+    },
+    styles::WARNING_COLOR,
 };
 
 pub struct GuestListInput {
@@ -66,13 +71,24 @@ impl GuestListInput {
         )],];
         let mut optional_row = row![];
         if let Some(id_card) = &guest.id_card {
-            optional_row = optional_row.push(text!("UCN: {}", id_card.ucn))
+            optional_row = if id_card.ucn.is_empty() {
+                optional_row.push(text!(
+                    "{}: {}",
+                    id_card.document_type,
+                    id_card.id_card_number
+                ))
+            } else {
+                optional_row.push(text!("UCN: {}", id_card.ucn))
+            }
         }
         if let Some(phone) = &guest.phone_number {
-            optional_row = optional_row.push(text!("Phone: {phone}"))
+            optional_row = optional_row.push(text!("Phone: {}", format_phone_number(phone)))
         }
 
         col = col.push(optional_row.spacing(10));
+        if let Some(warning) = guest.get_document_warning(Date::today()) {
+            col = col.push(text(warning).color(WARNING_COLOR));
+        }
         col = col.push(button("Add").on_press(on_selected(guest.id)));
 
         iced::widget::container(col.spacing(5))
@@ -113,30 +129,14 @@ impl GuestListInput {
         map_result: F,
     ) -> Task<AppMessage>
     where
-        F: Send
-            + Sync
-            + Fn(Result<GetGuestResult, String>) -> AppMessage
-            + Clone
-            + Send
-            + Sync
-            + 'static,
+        F: Send + Sync + Fn(ApiResult<Guest>) -> AppMessage + Clone + Send + Sync + 'static,
     {
         self.ids = guest_ids;
-        let token = if let Some(some) = global_state
-            .lock()
-            .unwrap()
-            .token
-            .as_ref()
-            .map(|t| t.token_string.clone())
-        {
-            some
-        } else {
-            return Task::done(AppMessage::TokenExpired);
-        };
+        let client = ApiClient::new(&global_state);
 
         const INITIAL_LOAD: usize = 5;
 
-        self.load_elements(token, INITIAL_LOAD, map_result)
+        self.load_elements(client, INITIAL_LOAD, map_result)
     }
 
     pub fn load_scrolled<F>(
@@ -146,44 +146,27 @@ impl GuestListInput {
         map_result: F,
     ) -> Task<AppMessage>
     where
-        F: Send
-            + Sync
-            + Fn(Result<GetGuestResult, String>) -> AppMessage
-            + Clone
-            + Send
-            + Sync
-            + 'static,
+        F: Send + Sync + Fn(ApiResult<Guest>) -> AppMessage + Clone + Send + Sync + 'static,
     {
-        let token = if let Some(some) = global_state
-            .lock()
-            .unwrap()
-            .token
-            .as_ref()
-            .map(|t| t.token_string.clone())
-        {
-            some
-        } else {
-            return Task::done(AppMessage::TokenExpired);
-        };
+        let client = ApiClient::new(&global_state);
 
         let number_to_load = (scroll_percent * self.ids.len() as f32) as usize;
 
-        self.load_elements(token, number_to_load, map_result)
+        self.load_elements(client, number_to_load, map_result)
     }
 
     pub fn update_loaded(&mut self, guest: Guest) {
         self.loaded.insert(guest.id, guest);
     }
 
-    fn load_elements<F>(&mut self, token: String, number: usize, map_result: F) -> Task<AppMessage>
+    fn load_elements<F>(
+        &mut self,
+        client: ApiClient,
+        number: usize,
+        map_result: F,
+    ) -> Task<AppMessage>
     where
-        F: Send
-            + Sync
-            + Fn(Result<GetGuestResult, String>) -> AppMessage
-            + Clone
-            + Send
-            + Sync
-            + 'static,
+        F: Send + Sync + Fn(ApiResult<Guest>) -> AppMessage + Clone + Send + Sync + 'static,
     {
         const EXTRA_TO_LOAD: usize = 5;
         let elements_to_load = (number + EXTRA_TO_LOAD).clamp(0, self.ids.len());
@@ -202,10 +185,7 @@ impl GuestListInput {
 
         let tasks: Vec<_> = to_load
             .into_iter()
-            .map(|id| {
-                let token_copy = token.clone();
-                Task::perform(get_guest(token_copy, id), map_result.clone())
-            })
+            .map(|id| Task::perform(get_guest(client.clone(), id), map_result.clone()))
             .collect();
 
         Task::batch(tasks)
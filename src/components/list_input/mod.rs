@@ -1,2 +1,3 @@
+pub mod booking_list_input;
 pub mod guest_list_input;
 pub mod room_list_input;
@@ -13,11 +13,16 @@ use uuid::Uuid;
 use crate::{
     app::{AppMessage, GlobalState},
     model::room::Room,
-    services::get_room::{get_room, GetRoomResult},
+    services::{
+        api_client::{ApiClient, ApiResult},
+        find_unoccupied_rooms::RoomOrder,
+        get_room::get_room,
+    },
 };
 
 pub struct RoomListInput {
     ids: Vec<Uuid>,
+    unsorted_ids: Vec<Uuid>,
     selected: Option<Uuid>,
     loaded: HashMap<Uuid, Room>,
 }
@@ -26,6 +31,7 @@ impl RoomListInput {
         const INITIAL_CAPACITY: usize = 100;
         Self {
             ids: Vec::with_capacity(INITIAL_CAPACITY),
+            unsorted_ids: Vec::with_capacity(INITIAL_CAPACITY),
             loaded: HashMap::with_capacity(INITIAL_CAPACITY),
             selected: None,
         }
@@ -116,31 +122,49 @@ impl RoomListInput {
         map_result: F,
     ) -> Task<AppMessage>
     where
-        F: Send
-            + Sync
-            + Fn(Result<GetRoomResult, String>) -> AppMessage
-            + Clone
-            + Send
-            + Sync
-            + 'static,
+        F: Send + Sync + Fn(ApiResult<Room>) -> AppMessage + Clone + Send + Sync + 'static,
     {
         self.selected = None;
+        self.unsorted_ids = room_ids.clone();
         self.ids = room_ids;
-        let token = if let Some(some) = global_state
-            .lock()
-            .unwrap()
-            .token
-            .as_ref()
-            .map(|t| t.token_string.clone())
-        {
-            some
-        } else {
-            return Task::done(map_result(Ok(GetRoomResult::Forbidden)));
-        };
+        let client = ApiClient::new(&global_state);
 
         const INITIAL_LOAD: usize = 5;
 
-        self.load_elements(token, INITIAL_LOAD, map_result)
+        self.load_elements(client, INITIAL_LOAD, map_result)
+    }
+
+    pub fn update_ids_with_loaded<F>(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        room_ids: Vec<Uuid>,
+        loaded: Vec<Room>,
+        map_result: F,
+    ) -> Task<AppMessage>
+    where
+        F: Send + Sync + Fn(ApiResult<Room>) -> AppMessage + Clone + Send + Sync + 'static,
+    {
+        for room in loaded {
+            self.loaded.insert(room.id, room);
+        }
+
+        self.update_ids(global_state, room_ids, map_result)
+    }
+
+    // Only possible once every room is loaded, returns false otherwise
+    pub fn sort(&mut self, order: RoomOrder) -> bool {
+        let Some(mut rooms) = self
+            .unsorted_ids
+            .iter()
+            .map(|id| self.loaded.get(id))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+        order.sort(&mut rooms);
+        self.ids = rooms.into_iter().map(|room| room.id).collect();
+
+        true
     }
 
     pub fn load_scrolled<F>(
@@ -150,44 +174,27 @@ impl RoomListInput {
         map_result: F,
     ) -> Task<AppMessage>
     where
-        F: Send
-            + Sync
-            + Fn(Result<GetRoomResult, String>) -> AppMessage
-            + Clone
-            + Send
-            + Sync
-            + 'static,
+        F: Send + Sync + Fn(ApiResult<Room>) -> AppMessage + Clone + Send + Sync + 'static,
     {
-        let token = if let Some(some) = global_state
-            .lock()
-            .unwrap()
-            .token
-            .as_ref()
-            .map(|t| t.token_string.clone())
-        {
-            some
-        } else {
-            return Task::done(map_result(Ok(GetRoomResult::Forbidden)));
-        };
+        let client = ApiClient::new(&global_state);
 
         let number_to_load = (scroll_percent * self.ids.len() as f32) as usize;
 
-        self.load_elements(token, number_to_load, map_result)
+        self.load_elements(client, number_to_load, map_result)
     }
 
     pub fn update_loaded(&mut self, room: Room) {
         self.loaded.insert(room.id, room);
     }
 
-    fn load_elements<F>(&mut self, token: String, number: usize, map_result: F) -> Task<AppMessage>
+    fn load_elements<F>(
+        &mut self,
+        client: ApiClient,
+        number: usize,
+        map_result: F,
+    ) -> Task<AppMessage>
     where
-        F: Send
-            + Sync
-            + Fn(Result<GetRoomResult, String>) -> AppMessage
-            + Clone
-            + Send
-            + Sync
-            + 'static,
+        F: Send + Sync + Fn(ApiResult<Room>) -> AppMessage + Clone + Send + Sync + 'static,
     {
         const EXTRA_TO_LOAD: usize = 5;
         let elements_to_load = (number + EXTRA_TO_LOAD).clamp(0, self.ids.len());
@@ -206,12 +213,13 @@ impl RoomListInput {
 
         let tasks: Vec<_> = to_load
             .into_iter()
-            .map(|id| {
-                let token_copy = token.clone();
-                Task::perform(get_room(token_copy, id), map_result.clone())
-            })
+            .map(|id| Task::perform(get_room(client.clone(), id), map_result.clone()))
             .collect();
 
         Task::batch(tasks)
     }
+
+    pub fn get_loaded(&self, id: Uuid) -> Option<&Room> {
+        self.loaded.get(&id)
+    }
 }
@@ -5,5 +5,7 @@ pub mod focus_chain;
 pub mod list_input;
 pub mod navigation_bar;
 pub mod notification;
+pub mod occupancy_grid;
+pub mod room_search;
 pub mod text_box;
 pub mod validator;
@@ -14,26 +14,76 @@ const BORDER_SIZE: f32 = 1.0;
 const BORDER_RADIUS: f32 = 8.0;
 const SHADOW_OFFSET: Vector<f32> = Vector::new(5.0, 5.0);
 const SHADOW_BLUR_RADIUS: f32 = 10.0;
+const HISTORY_BUTTON_WIDTH: f32 = (NAVIGATION_BUTTON_WIDTH - BUTTON_SPACING) / 2.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct HistoryState {
+    pub can_go_back: bool,
+    pub can_go_forward: bool,
+}
+
+fn view_history(history: HistoryState) -> Element<'static, AppMessage> {
+    row![
+        button("Back")
+            .on_press_maybe(history.can_go_back.then_some(AppMessage::NavigateBack))
+            .width(HISTORY_BUTTON_WIDTH),
+        button("Forward")
+            .on_press_maybe(
+                history
+                    .can_go_forward
+                    .then_some(AppMessage::NavigateForward)
+            )
+            .width(HISTORY_BUTTON_WIDTH),
+    ]
+    .spacing(BUTTON_SPACING)
+    .into()
+}
 
 pub fn view_admin(
     global_state: Arc<Mutex<GlobalState>>,
     current_screen: &dyn Screen,
+    history: HistoryState,
 ) -> Element<AppMessage> {
     row![
         add_container(
             column![
+                view_history(history),
                 button("Logout")
                     .on_press(AppMessage::Logout)
                     .width(NAVIGATION_BUTTON_WIDTH),
+                button("Home")
+                    .on_press(AppMessage::NavigateTo(ScreenType::Home))
+                    .width(NAVIGATION_BUTTON_WIDTH),
+                button("Today")
+                    .on_press(AppMessage::NavigateTo(ScreenType::Today))
+                    .width(NAVIGATION_BUTTON_WIDTH),
+                button("Rooms")
+                    .on_press(AppMessage::NavigateTo(ScreenType::Rooms))
+                    .width(NAVIGATION_BUTTON_WIDTH),
                 button("Add Room")
                     .on_press(AppMessage::NavigateTo(ScreenType::AddRoom))
                     .width(NAVIGATION_BUTTON_WIDTH),
+                button("Guests")
+                    .on_press(AppMessage::NavigateTo(ScreenType::Guests))
+                    .width(NAVIGATION_BUTTON_WIDTH),
                 button("Add Guest")
                     .on_press(AppMessage::NavigateTo(ScreenType::AddGuest))
                     .width(NAVIGATION_BUTTON_WIDTH),
+                button("Expiring documents")
+                    .on_press(AppMessage::NavigateTo(ScreenType::ExpiringDocuments))
+                    .width(NAVIGATION_BUTTON_WIDTH),
                 button("Book room")
                     .on_press(AppMessage::NavigateTo(ScreenType::BookRoom))
                     .width(NAVIGATION_BUTTON_WIDTH),
+                button("Bookings")
+                    .on_press(AppMessage::NavigateTo(ScreenType::Bookings))
+                    .width(NAVIGATION_BUTTON_WIDTH),
+                button("Occupancy")
+                    .on_press(AppMessage::NavigateTo(ScreenType::OccupancyCalendar))
+                    .width(NAVIGATION_BUTTON_WIDTH),
+                button("My account")
+                    .on_press(AppMessage::NavigateTo(ScreenType::MyAccount))
+                    .width(NAVIGATION_BUTTON_WIDTH),
             ]
             .spacing(BUTTON_SPACING)
             .into()
@@ -47,15 +97,27 @@ pub fn view_admin(
 pub fn view_user(
     global_state: Arc<Mutex<GlobalState>>,
     current_screen: &dyn Screen,
+    history: HistoryState,
 ) -> Element<AppMessage> {
     row![
         add_container(
             column![
+                view_history(history),
                 button("Logout")
                     .on_press(AppMessage::Logout)
                     .width(NAVIGATION_BUTTON_WIDTH),
-                button("Placeholder2").width(NAVIGATION_BUTTON_WIDTH),
-                button("Placeholder3").width(NAVIGATION_BUTTON_WIDTH),
+                button("Home")
+                    .on_press(AppMessage::NavigateTo(ScreenType::Home))
+                    .width(NAVIGATION_BUTTON_WIDTH),
+                button("My bookings")
+                    .on_press(AppMessage::NavigateTo(ScreenType::MyBookings))
+                    .width(NAVIGATION_BUTTON_WIDTH),
+                button("Search availability")
+                    .on_press(AppMessage::NavigateTo(ScreenType::SearchAvailability))
+                    .width(NAVIGATION_BUTTON_WIDTH),
+                button("My account")
+                    .on_press(AppMessage::NavigateTo(ScreenType::MyAccount))
+                    .width(NAVIGATION_BUTTON_WIDTH),
             ]
             .spacing(BUTTON_SPACING)
             .into()
@@ -10,7 +10,7 @@ use iced::{
 };
 use tokio::time::sleep;
 
-use crate::app::AppMessage;
+use crate::{app::AppMessage, styles::WARNING_COLOR};
 
 const NOTIFICATION_TIME: u64 = 4;
 const NOTIFICATION_TEXT_SIZE: f32 = 21.0;
@@ -21,6 +21,7 @@ const NOTIFICATION_BORDER_RADIUS: f32 = 3.0;
 pub enum NotificationType {
     Information,
     Success,
+    Warning,
     Error,
 }
 
@@ -55,6 +56,7 @@ impl Notification {
             let background = Some(match notification_type {
                 NotificationType::Information => Background::Color(palette.primary),
                 NotificationType::Success => Background::Color(palette.success),
+                NotificationType::Warning => Background::Color(WARNING_COLOR),
                 NotificationType::Error => Background::Color(palette.danger),
             });
 
@@ -0,0 +1,322 @@
+use chrono::NaiveDate;
+use iced::{
+    mouse,
+    widget::canvas::{self, event, Event, Frame, Geometry, Path, Program, Stroke, Text},
+    Color, Element, Pixels, Point, Rectangle, Renderer, Size, Theme,
+};
+use iced_aw::date_picker::Date;
+use uuid::Uuid;
+
+use crate::{
+    app::AppMessage,
+    model::{booking_status::BookingStatus, room::Room},
+    utils::{add_days, days_between, is_date_before, is_same_date},
+};
+
+const LABEL_WIDTH: f32 = 110.0;
+const DAY_WIDTH: f32 = 56.0;
+const HEADER_HEIGHT: f32 = 40.0;
+const ROW_HEIGHT: f32 = 28.0;
+const BAR_INSET: f32 = 4.0;
+const TEXT_SIZE: f32 = 13.0;
+const TEXT_PADDING: f32 = 6.0;
+
+pub enum GridRow<'a> {
+    Floor(i16),
+    Room(&'a Room),
+}
+
+pub struct GridBar {
+    pub booking_id: Uuid,
+    pub room_id: Uuid,
+    pub start_date: Date,
+    pub end_date: Date,
+    pub status: BookingStatus,
+    pub label: String,
+}
+
+pub struct OccupancyGrid<'a> {
+    rows: Vec<GridRow<'a>>,
+    bars: Vec<GridBar>,
+    start_date: Date,
+    days: i64,
+    on_empty_cell: fn(Uuid, Date) -> AppMessage,
+    on_booking: fn(Uuid) -> AppMessage,
+}
+impl<'a> OccupancyGrid<'a> {
+    pub fn new(
+        rows: Vec<GridRow<'a>>,
+        bars: Vec<GridBar>,
+        start_date: Date,
+        days: i64,
+        on_empty_cell: fn(Uuid, Date) -> AppMessage,
+        on_booking: fn(Uuid) -> AppMessage,
+    ) -> Self {
+        Self {
+            rows,
+            bars,
+            start_date,
+            days,
+            on_empty_cell,
+            on_booking,
+        }
+    }
+
+    pub fn view(self) -> Element<'a, AppMessage> {
+        let width = LABEL_WIDTH + DAY_WIDTH * self.days as f32;
+        let height = HEADER_HEIGHT + ROW_HEIGHT * self.rows.len() as f32;
+
+        canvas::Canvas::new(self).width(width).height(height).into()
+    }
+
+    fn get_cell(&self, position: Point) -> Option<(&'a Room, Date)> {
+        if position.x < LABEL_WIDTH || position.y < HEADER_HEIGHT {
+            return None;
+        }
+        let row = ((position.y - HEADER_HEIGHT) / ROW_HEIGHT) as usize;
+        let day = ((position.x - LABEL_WIDTH) / DAY_WIDTH) as i64;
+        if day >= self.days {
+            return None;
+        }
+
+        match self.rows.get(row) {
+            Some(GridRow::Room(room)) => Some((room, add_days(self.start_date, day))),
+            _ => None,
+        }
+    }
+
+    fn get_bar_at(&self, room_id: Uuid, date: Date) -> Option<&GridBar> {
+        self.bars.iter().find(|bar| {
+            bar.room_id == room_id
+                && !is_date_before(date, bar.start_date)
+                && is_date_before(date, bar.end_date)
+        })
+    }
+
+    fn get_cell_message(&self, room_id: Uuid, date: Date) -> Option<AppMessage> {
+        match self.get_bar_at(room_id, date) {
+            Some(bar) => Some((self.on_booking)(bar.booking_id)),
+            // Past nights can no longer be booked
+            None if is_date_before(date, Date::today()) => None,
+            None => Some((self.on_empty_cell)(room_id, date)),
+        }
+    }
+
+    fn get_bar_color(theme: &Theme, status: BookingStatus) -> Color {
+        let palette = theme.palette();
+        match status {
+            BookingStatus::Booked => palette.primary,
+            BookingStatus::CheckedIn => palette.success,
+            BookingStatus::CheckedOut | BookingStatus::Cancelled => palette.text.scale_alpha(0.4),
+        }
+    }
+
+    fn draw_text(frame: &mut Frame, content: String, position: Point, color: Color) {
+        frame.fill_text(Text {
+            content,
+            position,
+            color,
+            size: Pixels(TEXT_SIZE),
+            ..Default::default()
+        });
+    }
+
+    fn draw_header(&self, frame: &mut Frame, theme: &Theme) {
+        let palette = theme.palette();
+        let today = Date::today();
+
+        for day in 0..self.days {
+            let date = add_days(self.start_date, day);
+            let naive_date: NaiveDate = date.into();
+            let x = LABEL_WIDTH + DAY_WIDTH * day as f32;
+            if is_same_date(date, today) {
+                frame.fill_rectangle(
+                    Point::new(x, 0.0),
+                    Size::new(DAY_WIDTH, frame.height()),
+                    palette.primary.scale_alpha(0.1),
+                );
+            }
+            Self::draw_text(
+                frame,
+                naive_date.format("%a").to_string(),
+                Point::new(x + TEXT_PADDING, 4.0),
+                palette.text,
+            );
+            Self::draw_text(
+                frame,
+                naive_date.format("%d.%m").to_string(),
+                Point::new(x + TEXT_PADDING, 20.0),
+                palette.text,
+            );
+        }
+    }
+
+    fn draw_rows(&self, frame: &mut Frame, theme: &Theme) {
+        let palette = theme.palette();
+
+        for (index, row) in self.rows.iter().enumerate() {
+            let y = HEADER_HEIGHT + ROW_HEIGHT * index as f32;
+            let label = match row {
+                GridRow::Floor(floor) => {
+                    frame.fill_rectangle(
+                        Point::new(0.0, y),
+                        Size::new(frame.width(), ROW_HEIGHT),
+                        palette.text.scale_alpha(0.08),
+                    );
+                    format!("Floor {floor}")
+                }
+                GridRow::Room(room) => format!("  Room {}", room.room_number),
+            };
+            Self::draw_text(
+                frame,
+                label,
+                Point::new(TEXT_PADDING, y + TEXT_PADDING),
+                palette.text,
+            );
+        }
+    }
+
+    fn draw_grid_lines(&self, frame: &mut Frame, theme: &Theme) {
+        let stroke = Stroke::default()
+            .with_color(theme.palette().text.scale_alpha(0.2))
+            .with_width(1.0);
+
+        for day in 0..=self.days {
+            let x = LABEL_WIDTH + DAY_WIDTH * day as f32;
+            frame.stroke(
+                &Path::line(Point::new(x, 0.0), Point::new(x, frame.height())),
+                stroke,
+            );
+        }
+        for row in 0..=self.rows.len() {
+            let y = HEADER_HEIGHT + ROW_HEIGHT * row as f32;
+            frame.stroke(
+                &Path::line(Point::new(0.0, y), Point::new(frame.width(), y)),
+                stroke,
+            );
+        }
+    }
+
+    fn draw_bars(&self, frame: &mut Frame, theme: &Theme) {
+        let end_date = add_days(self.start_date, self.days);
+
+        for (index, row) in self.rows.iter().enumerate() {
+            let GridRow::Room(room) = row else {
+                continue;
+            };
+            let y = HEADER_HEIGHT + ROW_HEIGHT * index as f32;
+
+            for bar in self.bars.iter().filter(|bar| bar.room_id == room.id) {
+                if !is_date_before(bar.start_date, end_date)
+                    || !is_date_before(self.start_date, bar.end_date)
+                {
+                    continue;
+                }
+                let first_day = days_between(self.start_date, bar.start_date).max(0);
+                let last_day = days_between(self.start_date, bar.end_date).min(self.days);
+                let x = LABEL_WIDTH + DAY_WIDTH * first_day as f32;
+                let width = DAY_WIDTH * (last_day - first_day) as f32;
+
+                frame.fill_rectangle(
+                    Point::new(x + BAR_INSET, y + BAR_INSET),
+                    Size::new(width - BAR_INSET * 2.0, ROW_HEIGHT - BAR_INSET * 2.0),
+                    Self::get_bar_color(theme, bar.status),
+                );
+                // Approximate glyph width, the label is clipped to the bar
+                let max_chars = ((width - TEXT_PADDING * 2.0) / (TEXT_SIZE * 0.6)).max(0.0);
+                Self::draw_text(
+                    frame,
+                    bar.label.chars().take(max_chars as usize).collect(),
+                    Point::new(x + TEXT_PADDING, y + TEXT_PADDING),
+                    Color::WHITE,
+                );
+            }
+        }
+    }
+
+    fn draw_hover(&self, frame: &mut Frame, theme: &Theme, position: Point) {
+        let Some((room, date)) = self.get_cell(position) else {
+            return;
+        };
+        if self.get_bar_at(room.id, date).is_some() || is_date_before(date, Date::today()) {
+            return;
+        }
+
+        let row = ((position.y - HEADER_HEIGHT) / ROW_HEIGHT).floor();
+        let day = ((position.x - LABEL_WIDTH) / DAY_WIDTH).floor();
+        frame.fill_rectangle(
+            Point::new(
+                LABEL_WIDTH + DAY_WIDTH * day,
+                HEADER_HEIGHT + ROW_HEIGHT * row,
+            ),
+            Size::new(DAY_WIDTH, ROW_HEIGHT),
+            theme.palette().primary.scale_alpha(0.25),
+        );
+    }
+}
+impl Program<AppMessage> for OccupancyGrid<'_> {
+    type State = ();
+
+    fn update(
+        &self,
+        _state: &mut Self::State,
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<AppMessage>) {
+        let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event else {
+            return (event::Status::Ignored, None);
+        };
+        let Some((room, date)) = cursor
+            .position_in(bounds)
+            .and_then(|position| self.get_cell(position))
+        else {
+            return (event::Status::Ignored, None);
+        };
+
+        (
+            event::Status::Captured,
+            self.get_cell_message(room.id, date),
+        )
+    }
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        self.draw_header(&mut frame, theme);
+        self.draw_rows(&mut frame, theme);
+        self.draw_grid_lines(&mut frame, theme);
+        if let Some(position) = cursor.position_in(bounds) {
+            self.draw_hover(&mut frame, theme, position);
+        }
+        self.draw_bars(&mut frame, theme);
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        let clickable = cursor
+            .position_in(bounds)
+            .and_then(|position| self.get_cell(position))
+            .and_then(|(room, date)| self.get_cell_message(room.id, date))
+            .is_some();
+
+        if clickable {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
@@ -0,0 +1,465 @@
+use std::sync::{Arc, Mutex};
+
+use iced::{
+    widget::{button, column, pick_list, row, text, text_input},
+    Alignment::Center,
+    Element,
+    Length::Fill,
+    Task,
+};
+use iced_aw::date_picker::Date;
+use uuid::Uuid;
+
+use crate::{
+    app::{AppMessage, GlobalState},
+    components::{
+        date_input::DateInput,
+        focus_chain::FocusChain,
+        list_input::room_list_input::RoomListInput,
+        notification::NotificationType,
+        text_box::{
+            number_text_box::{NumberTextBox, NumberType},
+            text_box::TextElement,
+        },
+    },
+    model::{
+        bathroom_type::{BathroomFilter, BATHROOM_FILTERS},
+        bed::{BedSizeFilter, BED_SIZE_FILTERS},
+        room::Room,
+    },
+    services::{
+        api_client::{ApiClient, ApiResult},
+        find_unoccupied_rooms::{
+            search_unoccupied_rooms, FindUnoccupiedRoomsInput, RoomFilters, RoomOrder,
+            UnoccupiedRooms,
+        },
+    },
+    styles::ERROR_COLOR,
+    utils::show_notification,
+};
+
+#[derive(Debug, Clone)]
+pub enum RoomSearchMessage {
+    SetError(String),
+    ChangeMinimumCapacity(String),
+    ChangeMaximumCapacity(String),
+    ChangeBathroomFilter(BathroomFilter),
+    ChangeBedSizeFilter(BedSizeFilter),
+    ChangeMinimumFloor(String),
+    ChangeMaximumFloor(String),
+    ChangeMinimumPrice(String),
+    ChangeMaximumPrice(String),
+    ChangeOrder(RoomOrder),
+    ChangeStartDate(Date),
+    ToggleShowStartDate,
+    ChangeEndDate(Date),
+    ToggleShowEndDate,
+    FindFreeRooms,
+    FoundFreeRooms(UnoccupiedRooms),
+    RoomLoaded(Box<Room>),
+    ScrollRooms(f32),
+    SelectRoom(Uuid),
+}
+
+pub const MIN_CAPACITY_ID: &str = "Room Search Min Capacity";
+pub const MAX_CAPACITY_ID: &str = "Room Search Max Capacity";
+pub const MIN_FLOOR_ID: &str = "Room Search Min Floor";
+pub const MAX_FLOOR_ID: &str = "Room Search Max Floor";
+pub const MIN_PRICE_ID: &str = "Room Search Min Price";
+pub const MAX_PRICE_ID: &str = "Room Search Max Price";
+pub const ROOM_SEARCH_IDS: [&str; 6] = [
+    MIN_CAPACITY_ID,
+    MAX_CAPACITY_ID,
+    MIN_FLOOR_ID,
+    MAX_FLOOR_ID,
+    MIN_PRICE_ID,
+    MAX_PRICE_ID,
+];
+const ROOM_ORDERS: [RoomOrder; 4] = [
+    RoomOrder::Unsorted,
+    RoomOrder::Price,
+    RoomOrder::Floor,
+    RoomOrder::Capacity,
+];
+
+pub struct RoomSearch {
+    to_message: fn(RoomSearchMessage) -> AppMessage,
+    minimum_capacity_input: NumberTextBox,
+    maximum_capacity_input: NumberTextBox,
+    bathroom_filter: BathroomFilter,
+    bed_size_filter: BedSizeFilter,
+    minimum_floor_input: NumberTextBox,
+    maximum_floor_input: NumberTextBox,
+    minimum_price_input: NumberTextBox,
+    maximum_price_input: NumberTextBox,
+    order: RoomOrder,
+    start_date_input: DateInput,
+    end_date_input: DateInput,
+    select_room_input: RoomListInput,
+    searched_dates: Option<(Date, Date)>,
+    room_to_select: Option<Uuid>,
+    error: String,
+}
+impl RoomSearch {
+    pub fn new(to_message: fn(RoomSearchMessage) -> AppMessage) -> Self {
+        Self {
+            to_message,
+            minimum_capacity_input: NumberTextBox::new("", 2, NumberType::PositiveInteger),
+            maximum_capacity_input: NumberTextBox::new("", 2, NumberType::PositiveInteger),
+            bathroom_filter: BathroomFilter::Any,
+            bed_size_filter: BedSizeFilter::Any,
+            minimum_floor_input: NumberTextBox::new("", 3, NumberType::PositiveInteger),
+            maximum_floor_input: NumberTextBox::new("", 3, NumberType::PositiveInteger),
+            minimum_price_input: NumberTextBox::new("", 9, NumberType::Price),
+            maximum_price_input: NumberTextBox::new("", 9, NumberType::Price),
+            order: RoomOrder::Unsorted,
+            start_date_input: DateInput::new(
+                "Start",
+                Date::today(),
+                to_message(RoomSearchMessage::ToggleShowStartDate),
+            ),
+            end_date_input: DateInput::new(
+                "End",
+                Date::today(),
+                to_message(RoomSearchMessage::ToggleShowEndDate),
+            ),
+            select_room_input: RoomListInput::new(),
+            searched_dates: None,
+            room_to_select: None,
+            error: "".to_owned(),
+        }
+    }
+
+    pub fn prefill(&mut self, room_id: Uuid, start_date: Date, end_date: Date) {
+        self.start_date_input.update_date(start_date);
+        self.end_date_input.update_date(end_date);
+        self.room_to_select = Some(room_id);
+    }
+
+    pub fn start_date(&self) -> Date {
+        self.start_date_input.get_date()
+    }
+
+    pub fn end_date(&self) -> Date {
+        self.end_date_input.get_date()
+    }
+
+    pub fn searched_dates(&self) -> Option<(Date, Date)> {
+        self.searched_dates
+    }
+
+    pub fn selected_room(&self) -> Option<&Room> {
+        self.select_room_input
+            .get_selected()
+            .and_then(|id| self.select_room_input.get_loaded(id))
+    }
+
+    pub fn selected_room_id(&self) -> Option<Uuid> {
+        self.select_room_input.get_selected()
+    }
+
+    fn get_optional_number(number_str: &str) -> Option<i16> {
+        if number_str.is_empty() {
+            return None;
+        }
+
+        number_str.parse::<i16>().ok()
+    }
+
+    fn get_optional_price(price_str: &str) -> Option<i64> {
+        if price_str.is_empty() {
+            return None;
+        }
+
+        price_str
+            .parse::<f64>()
+            .ok()
+            .map(|price| (price * 100.0).round() as i64)
+    }
+
+    fn get_filters(&self) -> Result<RoomFilters, String> {
+        let filters = RoomFilters {
+            bathroom_type: match self.bathroom_filter {
+                BathroomFilter::Any => None,
+                BathroomFilter::Only(bathroom_type) => Some(bathroom_type),
+            },
+            bed_size: match self.bed_size_filter {
+                BedSizeFilter::Any => None,
+                BedSizeFilter::Only(bed_size) => Some(bed_size),
+            },
+            minimum_floor: Self::get_optional_number(self.minimum_floor_input.get_text()),
+            maximum_floor: Self::get_optional_number(self.maximum_floor_input.get_text()),
+            minimum_price: Self::get_optional_price(self.minimum_price_input.get_text()),
+            maximum_price: Self::get_optional_price(self.maximum_price_input.get_text()),
+            order: self.order,
+        };
+
+        if let (Some(min), Some(max)) = (filters.minimum_floor, filters.maximum_floor) {
+            if min > max {
+                return Err("Minimum floor is above maximum floor".to_owned());
+            }
+        }
+        if let (Some(min), Some(max)) = (filters.minimum_price, filters.maximum_price) {
+            if min > max {
+                return Err("Minimum price is above maximum price".to_owned());
+            }
+        }
+
+        Ok(filters)
+    }
+
+    fn find_free_rooms(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        let filters = match self.get_filters() {
+            Ok(filters) => filters,
+            Err(err) => {
+                self.error = err;
+                return Task::none();
+            }
+        };
+        let input = FindUnoccupiedRoomsInput {
+            start_date: self.start_date_input.get_date(),
+            end_date: self.end_date_input.get_date(),
+            minimum_capacity: Self::get_optional_number(self.minimum_capacity_input.get_text()),
+            maximum_capacity: Self::get_optional_number(self.maximum_capacity_input.get_text()),
+            filters,
+        };
+        self.searched_dates = Some((input.start_date, input.end_date));
+
+        let to_message = self.to_message;
+        Task::perform(
+            search_unoccupied_rooms(ApiClient::new(&global_state), input),
+            move |res| match res {
+                ApiResult::Success(rooms) => to_message(RoomSearchMessage::FoundFreeRooms(rooms)),
+                ApiResult::Forbidden => AppMessage::TokenExpired,
+                ApiResult::BadRequest(err) => to_message(RoomSearchMessage::SetError(err)),
+                ApiResult::Error(err) => {
+                    println!("Error finding free rooms: '{err}'");
+                    show_notification("Unexpected Error", NotificationType::Error)
+                }
+            },
+        )
+    }
+
+    fn map_get_room_result(
+        to_message: fn(RoomSearchMessage) -> AppMessage,
+    ) -> impl Fn(ApiResult<Room>) -> AppMessage + Clone + Send + Sync + 'static {
+        move |result| match result {
+            ApiResult::Success(room) => to_message(RoomSearchMessage::RoomLoaded(Box::new(room))),
+            ApiResult::Forbidden => AppMessage::TokenExpired,
+            ApiResult::BadRequest(err) => to_message(RoomSearchMessage::SetError(err)),
+            ApiResult::Error(err) => {
+                println!("Error fetching rooms: {err}");
+                show_notification("Unexpected_message", NotificationType::Error)
+            }
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        message: RoomSearchMessage,
+        global_state: Arc<Mutex<GlobalState>>,
+        focus_chain: &mut FocusChain,
+    ) -> Task<AppMessage> {
+        match message {
+            RoomSearchMessage::SetError(err) => {
+                self.error = err;
+                Task::none()
+            }
+            RoomSearchMessage::ChangeMinimumCapacity(min_capacity) => {
+                focus_chain.set_focus(Some(MIN_CAPACITY_ID));
+                self.minimum_capacity_input.update(min_capacity);
+                Task::none()
+            }
+            RoomSearchMessage::ChangeMaximumCapacity(max_capacity) => {
+                focus_chain.set_focus(Some(MAX_CAPACITY_ID));
+                self.maximum_capacity_input.update(max_capacity);
+                Task::none()
+            }
+            RoomSearchMessage::ChangeBathroomFilter(bathroom_filter) => {
+                self.bathroom_filter = bathroom_filter;
+                Task::none()
+            }
+            RoomSearchMessage::ChangeBedSizeFilter(bed_size_filter) => {
+                self.bed_size_filter = bed_size_filter;
+                Task::none()
+            }
+            RoomSearchMessage::ChangeMinimumFloor(floor) => {
+                focus_chain.set_focus(Some(MIN_FLOOR_ID));
+                self.minimum_floor_input.update(floor);
+                Task::none()
+            }
+            RoomSearchMessage::ChangeMaximumFloor(floor) => {
+                focus_chain.set_focus(Some(MAX_FLOOR_ID));
+                self.maximum_floor_input.update(floor);
+                Task::none()
+            }
+            RoomSearchMessage::ChangeMinimumPrice(price) => {
+                focus_chain.set_focus(Some(MIN_PRICE_ID));
+                self.minimum_price_input.update(price);
+                Task::none()
+            }
+            RoomSearchMessage::ChangeMaximumPrice(price) => {
+                focus_chain.set_focus(Some(MAX_PRICE_ID));
+                self.maximum_price_input.update(price);
+                Task::none()
+            }
+            RoomSearchMessage::ChangeOrder(order) => {
+                self.order = order;
+                // Results that were not fully loaded have to be searched again to sort them
+                if self.searched_dates.is_some() && !self.select_room_input.sort(order) {
+                    return self.find_free_rooms(global_state);
+                }
+                Task::none()
+            }
+            RoomSearchMessage::ChangeStartDate(date) => {
+                self.start_date_input.update_date(date);
+                self.start_date_input.toggle_show();
+                Task::none()
+            }
+            RoomSearchMessage::ToggleShowStartDate => {
+                self.start_date_input.toggle_show();
+                Task::none()
+            }
+            RoomSearchMessage::ChangeEndDate(date) => {
+                self.end_date_input.update_date(date);
+                self.end_date_input.toggle_show();
+                Task::none()
+            }
+            RoomSearchMessage::ToggleShowEndDate => {
+                self.end_date_input.toggle_show();
+                Task::none()
+            }
+            RoomSearchMessage::FindFreeRooms => self.find_free_rooms(global_state),
+            RoomSearchMessage::FoundFreeRooms(rooms) => {
+                let mut ids = rooms.ids;
+                self.error = "".to_owned();
+                let room_to_select = self
+                    .room_to_select
+                    .take()
+                    .filter(|room_id| ids.contains(room_id));
+                if let Some(room_id) = room_to_select {
+                    // Move the room to the top so it is loaded and visible
+                    ids.retain(|id| *id != room_id);
+                    ids.insert(0, room_id);
+                }
+                let task = self.select_room_input.update_ids_with_loaded(
+                    global_state,
+                    ids,
+                    rooms.loaded,
+                    Self::map_get_room_result(self.to_message),
+                );
+                // Sorting loads every room, so the results can be sorted here and
+                // switching back to no sorting restores the backend order
+                self.select_room_input.sort(self.order);
+                self.select_room_input.set_selected(room_to_select);
+                task
+            }
+            RoomSearchMessage::RoomLoaded(room) => {
+                self.select_room_input.update_loaded(*room);
+                Task::none()
+            }
+            RoomSearchMessage::ScrollRooms(amount) => self.select_room_input.load_scrolled(
+                global_state,
+                amount,
+                Self::map_get_room_result(self.to_message),
+            ),
+            RoomSearchMessage::SelectRoom(uuid) => {
+                self.select_room_input.set_selected(Some(uuid));
+                Task::none()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, AppMessage> {
+        let to_message = self.to_message;
+
+        column![
+            row![
+                text_input("Min capacity", self.minimum_capacity_input.get_text())
+                    .id(MIN_CAPACITY_ID)
+                    .on_input(move |x| to_message(RoomSearchMessage::ChangeMinimumCapacity(x)))
+                    .on_submit(to_message(RoomSearchMessage::FindFreeRooms))
+                    .align_x(Center)
+                    .width(120)
+                    .line_height(1.5),
+                text_input("Max capacity", self.maximum_capacity_input.get_text())
+                    .id(MAX_CAPACITY_ID)
+                    .on_input(move |x| to_message(RoomSearchMessage::ChangeMaximumCapacity(x)))
+                    .on_submit(to_message(RoomSearchMessage::FindFreeRooms))
+                    .align_x(Center)
+                    .width(120)
+                    .line_height(1.5),
+            ]
+            .spacing(10),
+            row![
+                text_input("Min floor", self.minimum_floor_input.get_text())
+                    .id(MIN_FLOOR_ID)
+                    .on_input(move |x| to_message(RoomSearchMessage::ChangeMinimumFloor(x)))
+                    .on_submit(to_message(RoomSearchMessage::FindFreeRooms))
+                    .align_x(Center)
+                    .width(120)
+                    .line_height(1.5),
+                text_input("Max floor", self.maximum_floor_input.get_text())
+                    .id(MAX_FLOOR_ID)
+                    .on_input(move |x| to_message(RoomSearchMessage::ChangeMaximumFloor(x)))
+                    .on_submit(to_message(RoomSearchMessage::FindFreeRooms))
+                    .align_x(Center)
+                    .width(120)
+                    .line_height(1.5),
+            ]
+            .spacing(10),
+            row![
+                text_input("Min price", self.minimum_price_input.get_text())
+                    .id(MIN_PRICE_ID)
+                    .on_input(move |x| to_message(RoomSearchMessage::ChangeMinimumPrice(x)))
+                    .on_submit(to_message(RoomSearchMessage::FindFreeRooms))
+                    .align_x(Center)
+                    .width(120)
+                    .line_height(1.5),
+                text_input("Max price", self.maximum_price_input.get_text())
+                    .id(MAX_PRICE_ID)
+                    .on_input(move |x| to_message(RoomSearchMessage::ChangeMaximumPrice(x)))
+                    .on_submit(to_message(RoomSearchMessage::FindFreeRooms))
+                    .align_x(Center)
+                    .width(120)
+                    .line_height(1.5),
+            ]
+            .spacing(10),
+            row![
+                pick_list(BATHROOM_FILTERS, Some(self.bathroom_filter), move |x| {
+                    to_message(RoomSearchMessage::ChangeBathroomFilter(x))
+                }),
+                pick_list(BED_SIZE_FILTERS, Some(self.bed_size_filter), move |x| {
+                    to_message(RoomSearchMessage::ChangeBedSizeFilter(x))
+                }),
+                pick_list(ROOM_ORDERS, Some(self.order), move |x| {
+                    to_message(RoomSearchMessage::ChangeOrder(x))
+                }),
+            ]
+            .spacing(10),
+            row![
+                self.start_date_input
+                    .view(move |x| to_message(RoomSearchMessage::ChangeStartDate(x))),
+                self.end_date_input
+                    .view(move |x| to_message(RoomSearchMessage::ChangeEndDate(x))),
+            ]
+            .spacing(10),
+            button("Find")
+                .on_press(to_message(RoomSearchMessage::FindFreeRooms))
+                .height(30)
+                .width(80),
+            text!("{}", self.error)
+                .color(ERROR_COLOR)
+                .size(18)
+                .align_x(Center)
+                .width(Fill),
+            self.select_room_input.view(
+                move |id| to_message(RoomSearchMessage::SelectRoom(id)),
+                move |x| to_message(RoomSearchMessage::ScrollRooms(x.relative_offset().y))
+            ),
+        ]
+        .spacing(20)
+        .align_x(Center)
+        .into()
+    }
+}
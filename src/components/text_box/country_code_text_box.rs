@@ -0,0 +1,30 @@
+use regex::Regex;
+
+use super::{regex_text_box::RegexTextBox, text_box::TextElement};
+
+pub struct CountryCodeTextBox {
+    text_box: RegexTextBox,
+}
+impl CountryCodeTextBox {
+    pub fn new<T>(initial_text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let regex = Regex::new("^[A-Z]*$").expect("Error creating country code text box");
+        Self {
+            text_box: RegexTextBox::new(initial_text, 3, regex),
+        }
+    }
+}
+impl TextElement for CountryCodeTextBox {
+    fn get_text(&self) -> &str {
+        self.text_box.get_text()
+    }
+
+    fn update<T>(&mut self, new_text: T)
+    where
+        T: Into<String>,
+    {
+        self.text_box.update(new_text.into().to_uppercase());
+    }
+}
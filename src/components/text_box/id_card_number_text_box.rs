@@ -10,7 +10,7 @@ impl IdCardNumberTextBox {
     where
         T: Into<String>,
     {
-        let regex = Regex::new("^[0-9]*$").expect("Error creating id card number text box");
+        let regex = Regex::new("^[A-Z0-9]*$").expect("Error creating id card number text box");
         Self {
             text_box: RegexTextBox::new(initial_text, 9, regex),
         }
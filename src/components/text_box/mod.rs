@@ -1,5 +1,7 @@
+pub mod country_code_text_box;
 pub mod id_card_number_text_box;
 pub mod number_text_box;
+pub mod personal_number_text_box;
 pub mod phone_number_text_box;
 pub mod regex_text_box;
 pub mod room_number_text_box;
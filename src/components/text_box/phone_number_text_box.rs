@@ -1,20 +1,38 @@
 use regex::Regex;
 
+use crate::model::phone_number::{normalize_phone_number, Country, DEFAULT_COUNTRY};
+
 use super::{regex_text_box::RegexTextBox, text_box::TextElement};
 
 pub struct PhoneNumberTextBox {
     text_box: RegexTextBox,
+    country: Country,
 }
 impl PhoneNumberTextBox {
     pub fn new<T>(initial_text: T) -> Self
     where
         T: Into<String>,
     {
-        let regex =
-            Regex::new("^(\\+[0-9]{0,15})?$").expect("Error creating phone number text box");
+        let regex = Regex::new("^\\+?[0-9 ()-]*$").expect("Error creating phone number text box");
         Self {
-            text_box: RegexTextBox::new(initial_text, 15, regex),
+            text_box: RegexTextBox::new(initial_text, 20, regex),
+            country: DEFAULT_COUNTRY,
+        }
+    }
+
+    pub fn get_country(&self) -> Country {
+        self.country
+    }
+
+    pub fn set_country(&mut self, country: Country) {
+        self.country = country;
+    }
+
+    pub fn get_normalized(&self) -> Result<Option<String>, String> {
+        if self.get_text().is_empty() {
+            return Ok(None);
         }
+        normalize_phone_number(self.get_text(), self.country).map(Some)
     }
 }
 impl TextElement for PhoneNumberTextBox {
@@ -0,0 +1,226 @@
+use std::{collections::HashMap, env, fs, path::Path, str::FromStr};
+
+use jsonwebtoken::Algorithm;
+use serde::Deserialize;
+
+use crate::{
+    constants::{
+        BASE_URL_ARG, BASE_URL_ENV, CONFIG_FILE_ARG, CONFIG_FILE_ENV, DEFAULT_BASE_URL,
+        DEFAULT_CONFIG_FILE, PROFILE_ARG, PROFILE_ENV,
+    },
+    security::JwtVerifier,
+};
+
+#[derive(Debug, Clone)]
+pub enum JwtKeySource {
+    Static(JwtVerifier),
+    Jwks { algorithm: Algorithm, url: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub base_url: String,
+    pub environment: Option<String>,
+    pub jwt_key: Option<JwtKeySource>,
+}
+impl AppConfig {
+    pub fn environment_description(&self) -> String {
+        match &self.environment {
+            Some(environment) => format!("{environment} ({})", self.base_url),
+            None => self.base_url.clone(),
+        }
+    }
+}
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            environment: None,
+            jwt_key: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JwtConfig {
+    algorithm: String,
+    secret: Option<String>,
+    public_key_file: Option<String>,
+    jwks_url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProfileConfig {
+    base_url: Option<String>,
+    jwt: Option<JwtConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigFile {
+    base_url: Option<String>,
+    profile: Option<String>,
+    jwt: Option<JwtConfig>,
+    #[serde(default)]
+    profiles: HashMap<String, ProfileConfig>,
+}
+
+#[derive(Debug, Default)]
+struct ConfigOverrides {
+    config_file: Option<String>,
+    profile: Option<String>,
+    base_url: Option<String>,
+}
+
+fn read_args() -> Result<ConfigOverrides, String> {
+    let mut overrides = ConfigOverrides::default();
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) => (name.to_owned(), Some(value.to_owned())),
+            None => (arg, None),
+        };
+        let target = match name.as_str() {
+            CONFIG_FILE_ARG => &mut overrides.config_file,
+            PROFILE_ARG => &mut overrides.profile,
+            BASE_URL_ARG => &mut overrides.base_url,
+            _ => return Err(format!("Unknown argument '{name}'")),
+        };
+        let value = match inline_value.or_else(|| args.next()) {
+            Some(some) => some,
+            None => return Err(format!("Missing value for '{name}'")),
+        };
+        *target = Some(value);
+    }
+
+    Ok(overrides)
+}
+
+fn read_env() -> ConfigOverrides {
+    ConfigOverrides {
+        config_file: env::var(CONFIG_FILE_ENV).ok(),
+        profile: env::var(PROFILE_ENV).ok(),
+        base_url: env::var(BASE_URL_ENV).ok(),
+    }
+}
+
+fn read_config_file(path: Option<&str>) -> Result<ConfigFile, String> {
+    let path = match path {
+        Some(some) => some,
+        None if Path::new(DEFAULT_CONFIG_FILE).exists() => DEFAULT_CONFIG_FILE,
+        None => return Ok(ConfigFile::default()),
+    };
+
+    println!("Reading config file '{path}'");
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("Error reading config file '{path}': {err}"))?;
+
+    serde_json::from_str(&contents)
+        .map_err(|err| format!("Error parsing config file '{path}': {err}"))
+}
+
+fn normalize_base_url(base_url: String) -> String {
+    if base_url.ends_with('/') {
+        base_url
+    } else {
+        base_url + "/"
+    }
+}
+
+fn resolve_url(base_url: &str, url: String) -> String {
+    if url.contains("://") {
+        url
+    } else {
+        base_url.to_owned() + url.trim_start_matches('/')
+    }
+}
+
+fn load_jwt_key(jwt: JwtConfig, base_url: &str) -> Result<JwtKeySource, String> {
+    let algorithm = match Algorithm::from_str(&jwt.algorithm) {
+        Ok(ok @ (Algorithm::HS256 | Algorithm::RS256 | Algorithm::ES256)) => ok,
+        _ => return Err(format!("Unsupported JWT algorithm '{}'", jwt.algorithm)),
+    };
+
+    match (algorithm, jwt.secret, jwt.public_key_file, jwt.jwks_url) {
+        (Algorithm::HS256, Some(secret), None, None) => {
+            Ok(JwtKeySource::Static(JwtVerifier::from_secret(&secret)))
+        }
+        (Algorithm::HS256, ..) => Err("HS256 requires only 'secret' to be set".to_owned()),
+        (_, None, Some(path), None) => {
+            let pem = fs::read(&path)
+                .map_err(|err| format!("Error reading public key '{path}': {err}"))?;
+            JwtVerifier::from_public_key(algorithm, &pem).map(JwtKeySource::Static)
+        }
+        (_, None, None, Some(url)) => Ok(JwtKeySource::Jwks {
+            algorithm,
+            url: resolve_url(base_url, url),
+        }),
+        _ => Err(format!(
+            "{algorithm:?} requires exactly one of 'publicKeyFile' or 'jwksUrl' to be set"
+        )),
+    }
+}
+
+pub fn load_config() -> Result<AppConfig, String> {
+    let args = read_args()?;
+    let env = read_env();
+    let mut config_file = read_config_file(args.config_file.or(env.config_file).as_deref())?;
+
+    let environment = args.profile.or(env.profile).or(config_file.profile);
+    let profile_config = match &environment {
+        Some(profile) => match config_file.profiles.remove(profile) {
+            Some(some) => some,
+            None => return Err(format!("Unknown profile '{profile}'")),
+        },
+        None => ProfileConfig::default(),
+    };
+
+    let base_url = args
+        .base_url
+        .or(env.base_url)
+        .or(profile_config.base_url)
+        .or(config_file.base_url)
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_owned());
+    let base_url = normalize_base_url(base_url);
+
+    let jwt_key = match profile_config.jwt.or(config_file.jwt) {
+        Some(jwt) => Some(load_jwt_key(jwt, &base_url)?),
+        None => None,
+    };
+
+    Ok(AppConfig {
+        base_url,
+        environment,
+        jwt_key,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE_URL: &str = "http://localhost:8080/";
+
+    #[test]
+    fn resolves_relative_urls_against_base_url() {
+        assert_eq!(
+            resolve_url(BASE_URL, "auth/jwks".to_owned()),
+            "http://localhost:8080/auth/jwks"
+        );
+        assert_eq!(
+            resolve_url(BASE_URL, "/auth/jwks".to_owned()),
+            "http://localhost:8080/auth/jwks"
+        );
+    }
+
+    #[test]
+    fn keeps_absolute_urls() {
+        assert_eq!(
+            resolve_url(BASE_URL, "https://auth.example.com/jwks".to_owned()),
+            "https://auth.example.com/jwks"
+        );
+    }
+}
@@ -1,11 +1,29 @@
-pub const REFRESH_TOKEN_PERIOD: u64 = 5 * 60;
+pub const REFRESH_TOKEN_MARGIN: u64 = 2 * 60;
+pub const SESSION_EXPIRY_WARNING: u64 = 60;
 
 pub const DEFAULT_WINDOW_SIZE: (f32, f32) = (800.0, 600.0);
+pub const MAX_HISTORY_LENGTH: usize = 20;
+pub const MAX_RECENT_GUESTS: usize = 5;
+pub const DASHBOARD_REFRESH_PERIOD: u64 = 60;
 
 pub const MAX_PASSWORD_LENGTH: usize = 24;
 pub const MAX_EMAIL_LENGTH: usize = 40;
 
-pub const BASE_URL: &str = "http://localhost:8080/";
+pub const DEFAULT_COUNTRY_CODE: &str = "BGR";
+
+pub const DEFAULT_BASE_URL: &str = "http://localhost:8080/";
+pub const DEFAULT_CONFIG_FILE: &str = "hotel_front.json";
+pub const CONFIG_FILE_ENV: &str = "HOTEL_CONFIG";
+pub const PROFILE_ENV: &str = "HOTEL_PROFILE";
+pub const BASE_URL_ENV: &str = "HOTEL_BASE_URL";
+pub const CONFIG_FILE_ARG: &str = "--config";
+pub const PROFILE_ARG: &str = "--profile";
+pub const BASE_URL_ARG: &str = "--base-url";
+
+pub const APP_CONFIG_DIR: &str = "hotel_front";
+pub const SESSION_FILE: &str = "session.dat";
+pub const SESSION_KEY_FILE: &str = "session.key";
+
 pub const LOGIN_PATH: &str = "auth/login";
 pub const ADD_ROOM_PATH: &str = "room";
 pub const ADD_GUEST_PATH: &str = "guest";
@@ -18,3 +36,17 @@ pub const FIND_UNOCCUPIED_ROOMS_PATH: &str = "booking/unoccupied";
 pub const GET_ROOM_PATH: &str = "room/";
 pub const GET_GUEST_PATH: &str = "guest/";
 pub const FIND_GUEST_PATH: &str = "guest";
+pub const CREATE_BOOKING_PATH: &str = "booking";
+pub const FIND_BOOKINGS_PATH: &str = "booking";
+pub const GET_BOOKING_PATH: &str = "booking/";
+pub const FIND_ROOMS_PATH: &str = "room";
+pub const UPDATE_ROOM_PATH: &str = "room/";
+pub const DELETE_ROOM_PATH: &str = "room/";
+pub const UPDATE_GUEST_PATH: &str = "guest/";
+pub const CANCEL_BOOKING_PATH: &str = "booking/";
+pub const CHANGE_BOOKING_DATES_PATH: &str = "booking/";
+pub const MOVE_BOOKING_PATH: &str = "booking/";
+pub const UPDATE_BOOKING_GUESTS_PATH: &str = "booking/";
+pub const CHECK_IN_BOOKING_PATH: &str = "booking/";
+pub const CHECK_OUT_BOOKING_PATH: &str = "booking/";
+pub const REQUEST_ROOM_CLEANING_PATH: &str = "room/";
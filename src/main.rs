@@ -4,28 +4,35 @@ use app::HotelApp;
 use constants::DEFAULT_WINDOW_SIZE;
 use iced_aw::iced_fonts::REQUIRED_FONT_BYTES;
 use styles::MAIN_THEME;
-use subscriptions::{
-    refresh_token_subscription::refresh_token_subscription,
-    select_text_input_subscription::select_text_input_subscription,
-};
+use subscriptions::subscriptions;
 
 mod app;
 mod components;
+mod config;
 mod constants;
 mod model;
 mod screens;
 mod security;
 mod services;
+mod session_store;
 mod styles;
 mod subscriptions;
 mod utils;
 
 fn main() -> iced::Result {
+    let config = match config::load_config() {
+        Ok(ok) => ok,
+        Err(err) => {
+            println!("Error loading config: {err}");
+            std::process::exit(1);
+        }
+    };
+    println!("Using backend: {}", config.environment_description());
+
     iced::application(HotelApp::title, HotelApp::update, HotelApp::view)
-        .subscription(refresh_token_subscription)
-        .subscription(select_text_input_subscription)
+        .subscription(subscriptions)
         .window_size(DEFAULT_WINDOW_SIZE)
         .theme(|_| MAIN_THEME)
         .font(REQUIRED_FONT_BYTES)
-        .run_with(HotelApp::new)
+        .run_with(move || HotelApp::new(config))
 }
@@ -1,3 +1,5 @@
+use std::fmt::{self, Display};
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -14,3 +16,23 @@ impl ToString for BathroomType {
         .to_owned()
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BathroomFilter {
+    Any,
+    Only(BathroomType),
+}
+impl Display for BathroomFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BathroomFilter::Any => write!(f, "Any bathroom"),
+            BathroomFilter::Only(bathroom_type) => write!(f, "{}", bathroom_type.to_string()),
+        }
+    }
+}
+
+pub const BATHROOM_FILTERS: [BathroomFilter; 3] = [
+    BathroomFilter::Any,
+    BathroomFilter::Only(BathroomType::Private),
+    BathroomFilter::Only(BathroomType::Shared),
+];
@@ -1,3 +1,5 @@
+use std::fmt::{self, Display};
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash)]
@@ -18,6 +20,36 @@ impl ToString for BedSize {
         .to_owned()
     }
 }
+impl BedSize {
+    pub fn capacity(self) -> i16 {
+        match self {
+            BedSize::Single => 1,
+            BedSize::SmallDouble | BedSize::Double | BedSize::KingSize => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BedSizeFilter {
+    Any,
+    Only(BedSize),
+}
+impl Display for BedSizeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BedSizeFilter::Any => write!(f, "Any bed"),
+            BedSizeFilter::Only(bed_size) => write!(f, "{} bed", bed_size.to_string()),
+        }
+    }
+}
+
+pub const BED_SIZE_FILTERS: [BedSizeFilter; 5] = [
+    BedSizeFilter::Any,
+    BedSizeFilter::Only(BedSize::Single),
+    BedSizeFilter::Only(BedSize::SmallDouble),
+    BedSizeFilter::Only(BedSize::Double),
+    BedSizeFilter::Only(BedSize::KingSize),
+];
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
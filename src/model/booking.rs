@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use iced_aw::date_picker::Date;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::utils::{days_between, is_date_before, string_to_date};
+
+use super::{booking_status::BookingStatus, guest::Guest, room::Room};
+
+#[derive(Debug, Clone)]
+pub struct Booking {
+    pub id: Uuid,
+    pub room_id: Uuid,
+    pub main_guest: Uuid,
+    pub other_guests: Vec<Uuid>,
+    pub start_date: Date,
+    pub end_date: Date,
+    pub status: BookingStatus,
+}
+impl Booking {
+    pub fn guest_ids(&self) -> Vec<Uuid> {
+        let mut ids = vec![self.main_guest];
+        ids.extend(self.other_guests.iter().copied());
+
+        ids
+    }
+
+    pub fn nights(&self) -> i64 {
+        days_between(self.start_date, self.end_date)
+    }
+
+    pub fn total_price(&self, room: &Room) -> i64 {
+        room.price * self.nights()
+    }
+
+    pub fn get_check_in_problems(&self, guests: &HashMap<Uuid, Guest>) -> Vec<String> {
+        let today = Date::today();
+        let mut problems = vec![];
+        if !guests.contains_key(&self.main_guest) {
+            problems.push("Main guest is missing".to_owned());
+        }
+        let missing_others = self
+            .other_guests
+            .iter()
+            .filter(|id| !guests.contains_key(id))
+            .count();
+        if missing_others > 0 {
+            problems.push(format!("{missing_others} other guests are missing"));
+        }
+
+        for guest in self.guest_ids().iter().filter_map(|id| guests.get(id)) {
+            let name = format!("{} {}", guest.first_name, guest.last_name);
+            match &guest.id_card {
+                None => problems.push(format!("{name} has no identity document")),
+                Some(id_card) if is_date_before(id_card.validity_date, today) => problems.push(
+                    format!("{name}'s document expired on {}", id_card.validity_date),
+                ),
+                Some(_) => {}
+            }
+        }
+
+        problems
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookingDto {
+    room_id: Uuid,
+    main_guest: Uuid,
+    other_guests: Vec<Uuid>,
+    start_date: String,
+    end_date: String,
+    #[serde(default)]
+    status: BookingStatus,
+}
+impl BookingDto {
+    pub fn convert_with_id(self, id: Uuid) -> Booking {
+        Booking {
+            id,
+            room_id: self.room_id,
+            main_guest: self.main_guest,
+            other_guests: self.other_guests,
+            start_date: string_to_date(&self.start_date),
+            end_date: string_to_date(&self.end_date),
+            status: self.status,
+        }
+    }
+}
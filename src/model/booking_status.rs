@@ -0,0 +1,22 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BookingStatus {
+    #[default]
+    Booked,
+    CheckedIn,
+    CheckedOut,
+    Cancelled,
+}
+impl Display for BookingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BookingStatus::Booked => write!(f, "Booked"),
+            BookingStatus::CheckedIn => write!(f, "Checked in"),
+            BookingStatus::CheckedOut => write!(f, "Checked out"),
+            BookingStatus::Cancelled => write!(f, "Cancelled"),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocumentType {
+    #[default]
+    NationalIdCard,
+    Passport,
+    ResidencePermit,
+}
+impl DocumentType {
+    pub const ALL: [DocumentType; 3] = [
+        DocumentType::NationalIdCard,
+        DocumentType::Passport,
+        DocumentType::ResidencePermit,
+    ];
+}
+impl Display for DocumentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentType::NationalIdCard => write!(f, "National ID card"),
+            DocumentType::Passport => write!(f, "Passport"),
+            DocumentType::ResidencePermit => write!(f, "Residence permit"),
+        }
+    }
+}
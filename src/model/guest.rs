@@ -2,7 +2,7 @@ use iced_aw::date_picker::Date;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::utils::string_to_date;
+use crate::utils::{is_date_before, string_to_date};
 
 use super::id_card::{IdCard, IdCardDto};
 
@@ -16,6 +16,21 @@ pub struct Guest {
     pub phone_number: Option<String>,
 }
 
+impl Guest {
+    pub fn get_document_warning(&self, needed_until: Date) -> Option<String> {
+        let id_card = self.id_card.as_ref()?;
+        let validity_date = id_card.validity_date;
+
+        if is_date_before(validity_date, Date::today()) {
+            Some(format!("Document expired on {validity_date}"))
+        } else if is_date_before(validity_date, needed_until) {
+            Some(format!("Document expires on {validity_date}"))
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GuestDto {
@@ -1,12 +1,17 @@
 use iced_aw::date_picker::Date;
 use serde::{Deserialize, Serialize};
 
-use crate::utils::string_to_date;
+use crate::{constants::DEFAULT_COUNTRY_CODE, utils::string_to_date};
+
+use super::document_type::DocumentType;
 
 #[derive(Debug, Clone)]
 pub struct IdCard {
+    pub document_type: DocumentType,
     pub ucn: String,
     pub id_card_number: String,
+    pub nationality: String,
+    pub issuing_country: String,
     pub issue_authority: String,
     pub issue_date: Date,
     pub validity_date: Date,
@@ -14,8 +19,11 @@ pub struct IdCard {
 impl Into<IdCardDto> for IdCard {
     fn into(self) -> IdCardDto {
         IdCardDto {
+            document_type: self.document_type,
             ucn: self.ucn,
             id_card_number: self.id_card_number,
+            nationality: self.nationality,
+            issuing_country: self.issuing_country,
             issue_authority: self.issue_authority,
             issue_date: self.issue_date.to_string(),
             validity: self.validity_date.to_string(),
@@ -23,11 +31,24 @@ impl Into<IdCardDto> for IdCard {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+// Guests saved before countries were recorded have Bulgarian documents
+fn default_country_code() -> String {
+    DEFAULT_COUNTRY_CODE.to_owned()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IdCardDto {
+    #[serde(default)]
+    document_type: DocumentType,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     ucn: String,
     id_card_number: String,
+    #[serde(default = "default_country_code")]
+    nationality: String,
+    #[serde(default = "default_country_code")]
+    issuing_country: String,
+    #[serde(default)]
     issue_authority: String,
     issue_date: String,
     validity: String,
@@ -35,8 +56,11 @@ pub struct IdCardDto {
 impl Into<IdCard> for IdCardDto {
     fn into(self) -> IdCard {
         IdCard {
+            document_type: self.document_type,
             ucn: self.ucn,
             id_card_number: self.id_card_number,
+            nationality: self.nationality,
+            issuing_country: self.issuing_country,
             issue_authority: self.issue_authority,
             issue_date: string_to_date(&self.issue_date),
             validity_date: string_to_date(&self.validity),
@@ -1,5 +1,11 @@
 pub mod bathroom_type;
 pub mod bed;
+pub mod booking;
+pub mod booking_status;
+pub mod document_type;
 pub mod guest;
 pub mod id_card;
+pub mod mrz;
+pub mod phone_number;
 pub mod room;
+pub mod ucn;
@@ -0,0 +1,419 @@
+use std::fmt::{self, Display};
+
+use chrono::{Datelike, Local, NaiveDate};
+use iced_aw::date_picker::Date;
+
+use super::document_type::DocumentType;
+
+const TD1_LINE_LENGTH: usize = 30;
+const TD1_LINES: usize = 3;
+const TD3_LINE_LENGTH: usize = 44;
+const TD3_LINES: usize = 2;
+const CHECK_DIGIT_WEIGHTS: [u32; 3] = [7, 3, 1];
+const FILLER: char = '<';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MrzField {
+    DocumentNumber,
+    DateOfBirth,
+    ExpiryDate,
+    PersonalNumber,
+    Composite,
+}
+impl Display for MrzField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MrzField::DocumentNumber => write!(f, "document number"),
+            MrzField::DateOfBirth => write!(f, "date of birth"),
+            MrzField::ExpiryDate => write!(f, "expiry date"),
+            MrzField::PersonalNumber => write!(f, "personal number"),
+            MrzField::Composite => write!(f, "whole document"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Mrz {
+    pub document_type: DocumentType,
+    pub issuing_country: String,
+    pub document_number: String,
+    pub nationality: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub date_of_birth: Option<Date>,
+    pub expiry_date: Option<Date>,
+    pub personal_number: String,
+    pub failed_checks: Vec<MrzField>,
+}
+impl Mrz {
+    pub fn is_complete(input: &str) -> bool {
+        let line_lengths: Vec<_> = input.split_whitespace().map(str::len).collect();
+
+        match line_lengths.as_slice() {
+            [TD1_LINE_LENGTH, TD1_LINE_LENGTH, TD1_LINE_LENGTH]
+            | [TD3_LINE_LENGTH, TD3_LINE_LENGTH] => true,
+            // Pasted text may come without line breaks
+            [length] if *length == TD1_LINE_LENGTH * TD1_LINES => true,
+            [length] if *length == TD3_LINE_LENGTH * TD3_LINES => {
+                input.trim_start().starts_with('P')
+            }
+            _ => false,
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let text: String = input
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        if !text
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == FILLER)
+        {
+            return Err("MRZ contains invalid characters".to_owned());
+        }
+
+        match text.len() {
+            len if len == TD1_LINE_LENGTH * TD1_LINES => Ok(Self::parse_td1(&text)),
+            len if len == TD3_LINE_LENGTH * TD3_LINES => Ok(Self::parse_td3(&text)),
+            len => Err(format!("Unrecognized MRZ format ({len} characters)")),
+        }
+    }
+
+    fn parse_td1(text: &str) -> Self {
+        let line1 = &text[..TD1_LINE_LENGTH];
+        let line2 = &text[TD1_LINE_LENGTH..TD1_LINE_LENGTH * 2];
+        let line3 = &text[TD1_LINE_LENGTH * 2..];
+        let (last_name, first_name) = parse_names(line3);
+
+        let mut failed_checks = vec![];
+        check(
+            &line1[5..14],
+            &line1[14..15],
+            MrzField::DocumentNumber,
+            &mut failed_checks,
+        );
+        check(
+            &line2[0..6],
+            &line2[6..7],
+            MrzField::DateOfBirth,
+            &mut failed_checks,
+        );
+        check(
+            &line2[8..14],
+            &line2[14..15],
+            MrzField::ExpiryDate,
+            &mut failed_checks,
+        );
+        let composite = [&line1[5..30], &line2[0..7], &line2[8..15], &line2[18..29]].concat();
+        check(
+            &composite,
+            &line2[29..30],
+            MrzField::Composite,
+            &mut failed_checks,
+        );
+
+        // Bulgarian cards carry the UCN in the second line, others in the first
+        let personal_number = match trim_filler(&line2[18..29]) {
+            number if number.is_empty() => trim_filler(&line1[15..30]),
+            number => number,
+        };
+
+        Self {
+            document_type: parse_document_type(&line1[0..2]),
+            issuing_country: trim_filler(&line1[2..5]),
+            document_number: trim_filler(&line1[5..14]),
+            nationality: trim_filler(&line2[15..18]),
+            first_name,
+            last_name,
+            date_of_birth: parse_date_of_birth(&line2[0..6]),
+            expiry_date: parse_expiry_date(&line2[8..14]),
+            personal_number,
+            failed_checks,
+        }
+    }
+
+    fn parse_td3(text: &str) -> Self {
+        let line1 = &text[..TD3_LINE_LENGTH];
+        let line2 = &text[TD3_LINE_LENGTH..];
+        let (last_name, first_name) = parse_names(&line1[5..]);
+
+        let mut failed_checks = vec![];
+        check(
+            &line2[0..9],
+            &line2[9..10],
+            MrzField::DocumentNumber,
+            &mut failed_checks,
+        );
+        check(
+            &line2[13..19],
+            &line2[19..20],
+            MrzField::DateOfBirth,
+            &mut failed_checks,
+        );
+        check(
+            &line2[21..27],
+            &line2[27..28],
+            MrzField::ExpiryDate,
+            &mut failed_checks,
+        );
+        // An empty personal number may use a filler instead of a check digit
+        if &line2[42..43] != "<" || line2[28..42].chars().any(|c| c != FILLER) {
+            check(
+                &line2[28..42],
+                &line2[42..43],
+                MrzField::PersonalNumber,
+                &mut failed_checks,
+            );
+        }
+        let composite = [&line2[0..10], &line2[13..20], &line2[21..43]].concat();
+        check(
+            &composite,
+            &line2[43..44],
+            MrzField::Composite,
+            &mut failed_checks,
+        );
+
+        Self {
+            document_type: parse_document_type(&line1[0..2]),
+            issuing_country: trim_filler(&line1[2..5]),
+            document_number: trim_filler(&line2[0..9]),
+            nationality: trim_filler(&line2[10..13]),
+            first_name,
+            last_name,
+            date_of_birth: parse_date_of_birth(&line2[13..19]),
+            expiry_date: parse_expiry_date(&line2[21..27]),
+            personal_number: trim_filler(&line2[28..42]),
+            failed_checks,
+        }
+    }
+}
+
+fn char_value(c: char) -> u32 {
+    match c {
+        '0'..='9' => c as u32 - '0' as u32,
+        'A'..='Z' => c as u32 - 'A' as u32 + 10,
+        _ => 0,
+    }
+}
+
+fn check_digit(data: &str) -> u32 {
+    data.chars()
+        .zip(CHECK_DIGIT_WEIGHTS.iter().cycle())
+        .map(|(c, weight)| char_value(c) * weight)
+        .sum::<u32>()
+        % 10
+}
+
+fn check(data: &str, digit: &str, field: MrzField, failed_checks: &mut Vec<MrzField>) {
+    let expected = digit.chars().next().map(char_value);
+    if expected != Some(check_digit(data)) {
+        failed_checks.push(field);
+    }
+}
+
+fn trim_filler(text: &str) -> String {
+    text.trim_matches(FILLER).replace(FILLER, " ")
+}
+
+fn capitalize(name: &str) -> String {
+    name.split(' ')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_string() + &chars.as_str().to_lowercase()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn parse_names(text: &str) -> (String, String) {
+    let mut parts = text.trim_end_matches(FILLER).splitn(2, "<<");
+    let last_name = parts.next().map(trim_filler).unwrap_or_default();
+    let first_name = parts.next().map(trim_filler).unwrap_or_default();
+
+    (capitalize(&last_name), capitalize(&first_name))
+}
+
+fn parse_document_type(code: &str) -> DocumentType {
+    match code {
+        code if code.starts_with('P') => DocumentType::Passport,
+        "IR" | "AR" | "RP" => DocumentType::ResidencePermit,
+        _ => DocumentType::NationalIdCard,
+    }
+}
+
+fn parse_date(text: &str, century: i32) -> Option<Date> {
+    let year: i32 = text.get(0..2)?.parse().ok()?;
+    let month = text.get(2..4)?.parse().ok()?;
+    let day = text.get(4..6)?.parse().ok()?;
+
+    NaiveDate::from_ymd_opt(century + year, month, day).map(Date::from)
+}
+
+fn parse_date_of_birth(text: &str) -> Option<Date> {
+    let current_year = Local::now().year() % 100;
+    let year: i32 = text.get(0..2)?.parse().ok()?;
+    let century = if year > current_year { 1900 } else { 2000 };
+
+    parse_date(text, century)
+}
+
+fn parse_expiry_date(text: &str) -> Option<Date> {
+    parse_date(text, 2000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Specimens from ICAO Doc 9303, parts 4 and 5
+    const TD3_LINE1: &str = "P<UTOERIKSSON<<ANNA<MARIA<<<<<<<<<<<<<<<<<<<";
+    const TD3_LINE2: &str = "L898902C36UTO7408122F1204159ZE184226B<<<<<10";
+    const TD1_LINE1: &str = "I<UTOD231458907<<<<<<<<<<<<<<<";
+    const TD1_LINE2: &str = "7408122F1204159UTO<<<<<<<<<<<6";
+    const TD1_LINE3: &str = "ERIKSSON<<ANNA<MARIA<<<<<<<<<<";
+
+    fn td3_with_line2(line2: &str) -> String {
+        format!("{TD3_LINE1}\n{line2}")
+    }
+
+    fn replace_char(line: &str, index: usize, c: char) -> String {
+        let mut chars: Vec<_> = line.chars().collect();
+        chars[index] = c;
+        chars.into_iter().collect()
+    }
+
+    fn assert_date(date: Option<Date>, year: i32, month: u32, day: u32) {
+        let date = date.expect("date should parse");
+        assert_eq!((date.year, date.month, date.day), (year, month, day));
+    }
+
+    #[test]
+    fn computes_check_digits() {
+        assert_eq!(check_digit("L898902C3"), 6);
+        assert_eq!(check_digit("740812"), 2);
+        assert_eq!(check_digit("ZE184226B<<<<<"), 1);
+        assert_eq!(check_digit("<<<<"), 0);
+    }
+
+    #[test]
+    fn parses_td3() {
+        let mrz = Mrz::parse(&td3_with_line2(TD3_LINE2)).unwrap();
+
+        assert_eq!(mrz.document_type, DocumentType::Passport);
+        assert_eq!(mrz.issuing_country, "UTO");
+        assert_eq!(mrz.document_number, "L898902C3");
+        assert_eq!(mrz.nationality, "UTO");
+        assert_eq!(mrz.last_name, "Eriksson");
+        assert_eq!(mrz.first_name, "Anna Maria");
+        assert_date(mrz.date_of_birth, 1974, 8, 12);
+        assert_date(mrz.expiry_date, 2012, 4, 15);
+        assert_eq!(mrz.personal_number, "ZE184226B");
+        assert!(mrz.failed_checks.is_empty());
+    }
+
+    #[test]
+    fn parses_td1() {
+        let input = format!("{TD1_LINE1}\n{TD1_LINE2}\n{TD1_LINE3}");
+        let mrz = Mrz::parse(&input).unwrap();
+
+        assert_eq!(mrz.document_type, DocumentType::NationalIdCard);
+        assert_eq!(mrz.issuing_country, "UTO");
+        assert_eq!(mrz.document_number, "D23145890");
+        assert_eq!(mrz.nationality, "UTO");
+        assert_eq!(mrz.last_name, "Eriksson");
+        assert_eq!(mrz.first_name, "Anna Maria");
+        assert_date(mrz.date_of_birth, 1974, 8, 12);
+        assert_date(mrz.expiry_date, 2012, 4, 15);
+        assert_eq!(mrz.personal_number, "");
+        assert!(mrz.failed_checks.is_empty());
+    }
+
+    #[test]
+    fn reports_each_failed_td3_check() {
+        let cases = [
+            (9, '5', MrzField::DocumentNumber),
+            (19, '3', MrzField::DateOfBirth),
+            (27, '8', MrzField::ExpiryDate),
+            (42, '2', MrzField::PersonalNumber),
+        ];
+
+        for (index, c, field) in cases {
+            let line2 = replace_char(TD3_LINE2, index, c);
+            let mrz = Mrz::parse(&td3_with_line2(&line2)).unwrap();
+            assert_eq!(mrz.failed_checks, [field, MrzField::Composite]);
+        }
+    }
+
+    #[test]
+    fn reports_failed_composite_check() {
+        let line2 = replace_char(TD3_LINE2, 43, '1');
+        let mrz = Mrz::parse(&td3_with_line2(&line2)).unwrap();
+
+        assert_eq!(mrz.failed_checks, [MrzField::Composite]);
+    }
+
+    #[test]
+    fn reports_failed_td1_checks() {
+        let line1 = replace_char(TD1_LINE1, 14, '8');
+        let input = format!("{line1}\n{TD1_LINE2}\n{TD1_LINE3}");
+        let mrz = Mrz::parse(&input).unwrap();
+
+        assert_eq!(
+            mrz.failed_checks,
+            [MrzField::DocumentNumber, MrzField::Composite]
+        );
+    }
+
+    #[test]
+    fn accepts_empty_td3_personal_number_with_filler_check_digit() {
+        let line2 = "L898902C36UTO7408122F1204159<<<<<<<<<<<<<<<8";
+        let mrz = Mrz::parse(&td3_with_line2(line2)).unwrap();
+
+        assert_eq!(mrz.personal_number, "");
+        assert!(mrz.failed_checks.is_empty());
+    }
+
+    #[test]
+    fn parses_paste_without_newlines() {
+        let td3 = format!("{TD3_LINE1}{TD3_LINE2}");
+        let td1 = format!("{TD1_LINE1}{TD1_LINE2}{TD1_LINE3}");
+
+        assert!(Mrz::is_complete(&td3));
+        assert!(Mrz::is_complete(&td1));
+        assert_eq!(Mrz::parse(&td3).unwrap().document_number, "L898902C3");
+        assert_eq!(Mrz::parse(&td1).unwrap().document_number, "D23145890");
+    }
+
+    #[test]
+    fn detects_incomplete_input() {
+        assert!(!Mrz::is_complete(TD3_LINE1));
+        assert!(!Mrz::is_complete(&format!("{TD1_LINE1}\n{TD1_LINE2}")));
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(Mrz::parse(&td3_with_line2(&TD3_LINE2.replace('Z', "-"))).is_err());
+        assert!(Mrz::parse(TD3_LINE1).is_err());
+    }
+
+    #[test]
+    fn picks_century_of_date_of_birth() {
+        let current_year = Local::now().year();
+        let this_year = format!("{:02}0101", current_year % 100);
+        let next_year = format!("{:02}0101", (current_year + 1) % 100);
+
+        assert_date(parse_date_of_birth(&this_year), current_year, 1, 1);
+        assert_date(
+            parse_date_of_birth(&next_year),
+            1900 + (current_year + 1) % 100,
+            1,
+            1,
+        );
+        assert!(parse_date_of_birth("741312").is_none());
+    }
+}
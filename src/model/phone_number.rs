@@ -0,0 +1,222 @@
+use std::fmt::{self, Display};
+
+const MIN_NATIONAL_LENGTH: usize = 6;
+const MIN_E164_LENGTH: usize = 8;
+const MAX_E164_LENGTH: usize = 15;
+const TRUNK_PREFIX: char = '0';
+const INTERNATIONAL_PREFIX: &str = "00";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Country {
+    pub name: &'static str,
+    pub dial_code: &'static str,
+}
+impl Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (+{})", self.name, self.dial_code)
+    }
+}
+
+pub const DEFAULT_COUNTRY: Country = COUNTRIES[0];
+pub const COUNTRIES: [Country; 14] = [
+    Country {
+        name: "Bulgaria",
+        dial_code: "359",
+    },
+    Country {
+        name: "Greece",
+        dial_code: "30",
+    },
+    Country {
+        name: "Romania",
+        dial_code: "40",
+    },
+    Country {
+        name: "Turkey",
+        dial_code: "90",
+    },
+    Country {
+        name: "Serbia",
+        dial_code: "381",
+    },
+    Country {
+        name: "North Macedonia",
+        dial_code: "389",
+    },
+    Country {
+        name: "Germany",
+        dial_code: "49",
+    },
+    Country {
+        name: "Austria",
+        dial_code: "43",
+    },
+    Country {
+        name: "France",
+        dial_code: "33",
+    },
+    Country {
+        name: "Spain",
+        dial_code: "34",
+    },
+    Country {
+        name: "United Kingdom",
+        dial_code: "44",
+    },
+    Country {
+        name: "Ukraine",
+        dial_code: "380",
+    },
+    Country {
+        name: "Russia",
+        dial_code: "7",
+    },
+    Country {
+        name: "United States",
+        dial_code: "1",
+    },
+];
+
+// Dial codes can prefix each other (1 and 1xxx), so the longest matching code wins
+fn find_country_in(countries: &[Country], international: &str) -> Option<Country> {
+    countries
+        .iter()
+        .filter(|country| international.starts_with(country.dial_code))
+        .max_by_key(|country| country.dial_code.len())
+        .copied()
+}
+
+fn find_country(international: &str) -> Option<Country> {
+    find_country_in(&COUNTRIES, international)
+}
+
+pub fn normalize_phone_number(input: &str, country: Country) -> Result<String, String> {
+    let trimmed = input.trim();
+    let digits: String = trimmed.chars().filter(|c| c.is_ascii_digit()).collect();
+
+    let international = if trimmed.starts_with('+') {
+        digits
+    } else if let Some(rest) = digits.strip_prefix(INTERNATIONAL_PREFIX) {
+        rest.to_owned()
+    } else if let Some(rest) = digits.strip_prefix(TRUNK_PREFIX) {
+        format!("{}{rest}", country.dial_code)
+    } else if find_country(&digits) == Some(country) {
+        digits
+    } else {
+        format!("{}{digits}", country.dial_code)
+    };
+
+    let min_length = find_country(&international).map_or(MIN_E164_LENGTH, |country| {
+        country.dial_code.len() + MIN_NATIONAL_LENGTH
+    });
+    if international.len() < min_length || international.len() > MAX_E164_LENGTH {
+        return Err("Invalid phone number".to_owned());
+    }
+
+    Ok(format!("+{international}"))
+}
+
+fn group_digits(digits: &str) -> String {
+    digits
+        .as_bytes()
+        .chunks(3)
+        .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn format_phone_number(phone_number: &str) -> String {
+    let Some(international) = phone_number.strip_prefix('+') else {
+        return phone_number.to_owned();
+    };
+    // Foreign numbers are shown as E.164, their national groupings vary too much
+    match find_country(international) {
+        Some(country) if country == DEFAULT_COUNTRY => format!(
+            "{TRUNK_PREFIX}{}",
+            group_digits(&international[country.dial_code.len()..])
+        ),
+        _ => phone_number.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNITED_STATES: Country = COUNTRIES[13];
+
+    #[test]
+    fn normalizes_national_numbers() {
+        assert_eq!(
+            normalize_phone_number("0888 123 456", DEFAULT_COUNTRY).unwrap(),
+            "+359888123456"
+        );
+        assert_eq!(
+            normalize_phone_number("888123456", DEFAULT_COUNTRY).unwrap(),
+            "+359888123456"
+        );
+        assert_eq!(
+            normalize_phone_number("(212) 555-0100", UNITED_STATES).unwrap(),
+            "+12125550100"
+        );
+    }
+
+    #[test]
+    fn normalizes_international_numbers() {
+        assert_eq!(
+            normalize_phone_number("+49 30 1234567", DEFAULT_COUNTRY).unwrap(),
+            "+49301234567"
+        );
+        assert_eq!(
+            normalize_phone_number("0049 30 1234567", DEFAULT_COUNTRY).unwrap(),
+            "+49301234567"
+        );
+        assert_eq!(
+            normalize_phone_number("359888123456", DEFAULT_COUNTRY).unwrap(),
+            "+359888123456"
+        );
+        assert_eq!(
+            normalize_phone_number("12125550100", UNITED_STATES).unwrap(),
+            "+12125550100"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_lengths() {
+        assert!(normalize_phone_number("0888", DEFAULT_COUNTRY).is_err());
+        assert!(normalize_phone_number("+3591234", DEFAULT_COUNTRY).is_err());
+        assert!(normalize_phone_number("+1234567890123456", DEFAULT_COUNTRY).is_err());
+    }
+
+    #[test]
+    fn matches_longest_dial_code() {
+        let antigua = Country {
+            name: "Antigua and Barbuda",
+            dial_code: "1268",
+        };
+        let countries = [UNITED_STATES, antigua];
+
+        assert_eq!(find_country_in(&countries, "12684601234"), Some(antigua));
+        assert_eq!(
+            find_country_in(&countries, "12125550100"),
+            Some(UNITED_STATES)
+        );
+        assert_eq!(find_country_in(&countries, "359888123456"), None);
+    }
+
+    #[test]
+    fn formats_bulgarian_numbers_nationally() {
+        assert_eq!(format_phone_number("+359888123456"), "0888 123 456");
+    }
+
+    #[test]
+    fn keeps_foreign_numbers_as_e164() {
+        assert_eq!(format_phone_number("+12125550100"), "+12125550100");
+        assert_eq!(format_phone_number("+49301234567"), "+49301234567");
+    }
+
+    #[test]
+    fn keeps_unknown_formats() {
+        assert_eq!(format_phone_number("0888123456"), "0888123456");
+    }
+}
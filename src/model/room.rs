@@ -13,3 +13,11 @@ pub struct Room {
     pub bathroom_type: BathroomType,
     pub beds: Vec<Bed>,
 }
+impl Room {
+    pub fn capacity(&self) -> i16 {
+        self.beds
+            .iter()
+            .map(|bed| bed.count * bed.bed_size.capacity())
+            .sum()
+    }
+}
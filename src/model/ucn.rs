@@ -0,0 +1,134 @@
+use std::fmt::{self, Display};
+
+use chrono::NaiveDate;
+use iced_aw::date_picker::Date;
+
+const UCN_LENGTH: usize = 10;
+const CHECKSUM_WEIGHTS: [u32; 9] = [2, 4, 8, 5, 10, 9, 7, 3, 6];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gender {
+    Male,
+    Female,
+}
+impl Display for Gender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Gender::Male => write!(f, "Male"),
+            Gender::Female => write!(f, "Female"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Ucn {
+    pub date_of_birth: Date,
+    pub gender: Gender,
+}
+impl Ucn {
+    pub fn parse(ucn: &str) -> Result<Self, String> {
+        let digits: Vec<u32> = ucn.chars().filter_map(|c| c.to_digit(10)).collect();
+        if ucn.len() != UCN_LENGTH || digits.len() != UCN_LENGTH {
+            return Err("UCN must be 10 digits".to_owned());
+        }
+
+        let checksum = CHECKSUM_WEIGHTS
+            .iter()
+            .zip(&digits)
+            .map(|(weight, digit)| weight * digit)
+            .sum::<u32>()
+            % 11
+            % 10;
+        if checksum != digits[9] {
+            return Err("Invalid UCN checksum".to_owned());
+        }
+
+        let year = (digits[0] * 10 + digits[1]) as i32;
+        let month = digits[2] * 10 + digits[3];
+        let day = digits[4] * 10 + digits[5];
+        // The month carries the century: +20 for 1800-1899 and +40 for 2000-2099
+        let (year, month) = match month {
+            1..=12 => (1900 + year, month),
+            21..=32 => (1800 + year, month - 20),
+            41..=52 => (2000 + year, month - 40),
+            _ => return Err("Invalid UCN birth month".to_owned()),
+        };
+        let date_of_birth = NaiveDate::from_ymd_opt(year, month, day)
+            .ok_or_else(|| "Invalid UCN birth date".to_owned())?;
+
+        let gender = if digits[8].is_multiple_of(2) {
+            Gender::Male
+        } else {
+            Gender::Female
+        };
+
+        Ok(Self {
+            date_of_birth: date_of_birth.into(),
+            gender,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_date(date: Date, year: i32, month: u32, day: u32) {
+        assert_eq!((date.year, date.month, date.day), (year, month, day));
+    }
+
+    #[test]
+    fn parses_1900s_ucn() {
+        let ucn = Ucn::parse("7501020018").unwrap();
+        assert_date(ucn.date_of_birth, 1975, 1, 2);
+        assert_eq!(ucn.gender, Gender::Female);
+    }
+
+    #[test]
+    fn parses_1800s_ucn() {
+        let ucn = Ucn::parse("9922281235").unwrap();
+        assert_date(ucn.date_of_birth, 1899, 2, 28);
+    }
+
+    #[test]
+    fn parses_2000s_ucn() {
+        let ucn = Ucn::parse("0445123450").unwrap();
+        assert_date(ucn.date_of_birth, 2004, 5, 12);
+    }
+
+    #[test]
+    fn parses_gender_from_ninth_digit() {
+        assert_eq!(Ucn::parse("8506157469").unwrap().gender, Gender::Male);
+        assert_eq!(Ucn::parse("0445123450").unwrap().gender, Gender::Female);
+    }
+
+    #[test]
+    fn rejects_wrong_checksum() {
+        assert_eq!(
+            Ucn::parse("7501020019").unwrap_err(),
+            "Invalid UCN checksum"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_month() {
+        assert_eq!(
+            Ucn::parse("7513020014").unwrap_err(),
+            "Invalid UCN birth month"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_day() {
+        assert_eq!(
+            Ucn::parse("7502300013").unwrap_err(),
+            "Invalid UCN birth date"
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_length_and_letters() {
+        assert!(Ucn::parse("750102001").is_err());
+        assert!(Ucn::parse("75010200A8").is_err());
+    }
+}
@@ -1,36 +1,50 @@
 use std::sync::{Arc, Mutex};
 
 use iced::{
-    widget::{button, column, scrollable, text, text_input},
+    widget::{button, column, container, pick_list, row, scrollable, text, text_input, Column},
     Alignment::Center,
     Element,
     Length::Fill,
-    Task,
+    Task, Theme,
 };
 use iced_aw::date_picker::Date;
 use uuid::Uuid;
 
 use crate::{
-    app::{AppMessage, GlobalState, Screen},
+    app::{AppMessage, GlobalState, Screen, ScreenType},
     components::{
         checkbox::Checkbox,
         date_input::DateInput,
         focus_chain::FocusChain,
         notification::NotificationType,
         text_box::{
+            country_code_text_box::CountryCodeTextBox,
             id_card_number_text_box::IdCardNumberTextBox,
+            personal_number_text_box::PersonalNumberTextBox,
             phone_number_text_box::PhoneNumberTextBox,
             text_box::{TextBox, TextElement},
             ucn_text_box::UcnTextBox,
         },
     },
-    model::id_card::IdCard,
+    constants::DEFAULT_COUNTRY_CODE,
+    model::{
+        document_type::DocumentType,
+        guest::Guest,
+        id_card::IdCard,
+        mrz::{Mrz, MrzField},
+        phone_number::{format_phone_number, Country, COUNTRIES},
+        ucn::Ucn,
+    },
     services::{
         self,
-        add_guest::{AddGuestInput, AddGuestResult},
+        add_guest::AddGuestInput,
+        api_client::{ApiClient, ApiResult},
+        find_guest::{find_guests_matching_any, FindGuestInput},
+        get_guest::get_guest,
+        update_guest::{update_guest, UpdateGuestInput},
     },
     styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TEXT_BOX_WIDTH, TITLE_FONT_SIZE},
-    utils::show_notification,
+    utils::{is_same_date, show_notification},
 };
 
 #[derive(Debug, Clone)]
@@ -39,8 +53,12 @@ pub enum AddGuestMessage {
     ChangeLastName(String),
     ChangeCheckbox(bool),
     ChangePhoneNumber(String),
+    ChangePhoneCountry(Country),
+    ChangeDocumentType(DocumentType),
     ChangeUcn(String),
     ChangeIdCardNumber(String),
+    ChangeNationality(String),
+    ChangeIssuingCountry(String),
     ChangeIdCardIssueAuthority(String),
     ToggleShowIssueDate,
     ChangeIdCardIssueDate(Date),
@@ -48,51 +66,73 @@ pub enum AddGuestMessage {
     ChangeIdCardValidityDate(Date),
     ToggleShowDateOfBirth,
     ChangeDateOfBirth(Date),
+    ChangeMrz(String),
+    MrzLineEnd,
     ShowError(String),
     AddGuest,
     GuestAdded(Uuid),
+    GuestUpdated,
+    DuplicatesFound(Vec<Uuid>),
+    DuplicateLoaded(Box<Guest>),
+    UseExistingGuest(Uuid),
+    ConfirmCreateGuest,
+    CancelCreateGuest,
 }
 
+const MRZ_ID: &str = "Register MRZ";
 const FIRST_NAME_ID: &str = "Register First Name";
 const LAST_NAME_ID: &str = "Register Last Name";
 const PHONE_NUMBER_ID: &str = "Register Phone Number";
 const ID_CARD_UCN_ID: &str = "Register ID Card UCN";
 const ID_CARD_NUMBER_ID: &str = "Register ID Card Number";
+const ID_CARD_NATIONALITY_ID: &str = "Register ID Card Nationality";
+const ID_CARD_ISSUING_COUNTRY_ID: &str = "Register ID Card Issuing Country";
 const ID_CARD_ISSUE_AUTHORITY_ID: &str = "Register ID Card Issue Authority";
-const FOCUS_IDS_WITHOUT_CARD: [&str; 3] = [FIRST_NAME_ID, LAST_NAME_ID, PHONE_NUMBER_ID];
-const FOCUS_IDS_WITH_CARD: [&str; 6] = [
-    FIRST_NAME_ID,
-    LAST_NAME_ID,
-    PHONE_NUMBER_ID,
-    ID_CARD_UCN_ID,
-    ID_CARD_NUMBER_ID,
-    ID_CARD_ISSUE_AUTHORITY_ID,
-];
+const FOCUS_IDS_WITHOUT_CARD: [&str; 4] = [MRZ_ID, FIRST_NAME_ID, LAST_NAME_ID, PHONE_NUMBER_ID];
+const NAME_MAX_LENGTH: usize = 20;
+const MRZ_MAX_LENGTH: usize = 100;
 
 pub struct AddGuestScreen {
     error: String,
+    mrz_input: TextBox,
+    mrz_failed_checks: Vec<MrzField>,
     first_name_input: TextBox,
     last_name_input: TextBox,
     has_id_card_checkbox: Checkbox,
     phone_number_input: PhoneNumberTextBox,
     date_of_birth_input: DateInput,
+    date_of_birth_entered: bool,
+    phone_number_edited: bool,
     id_card_ucn_input: UcnTextBox,
+    personal_number_input: PersonalNumberTextBox,
+    document_type: DocumentType,
     id_card_number_input: IdCardNumberTextBox,
+    id_card_nationality_input: CountryCodeTextBox,
+    id_card_issuing_country_input: CountryCodeTextBox,
     id_card_issue_authority_input: TextBox,
     id_card_issue_date_input: DateInput,
     id_card_validity_input: DateInput,
     focus_chain: FocusChain,
+    editing: Option<Guest>,
+    duplicates: Option<Vec<Guest>>,
+    checked_duplicate_queries: Vec<FindGuestInput>,
 }
 impl AddGuestScreen {
     pub fn new() -> Self {
         Self {
             error: "".to_owned(),
-            first_name_input: TextBox::new("", 20),
-            last_name_input: TextBox::new("", 20),
-            has_id_card_checkbox: Checkbox::new("Id card", false),
+            mrz_input: TextBox::new("", MRZ_MAX_LENGTH),
+            mrz_failed_checks: vec![],
+            first_name_input: TextBox::new("", NAME_MAX_LENGTH),
+            last_name_input: TextBox::new("", NAME_MAX_LENGTH),
+            has_id_card_checkbox: Checkbox::new("Identity document", false),
             phone_number_input: PhoneNumberTextBox::new(""),
             id_card_ucn_input: UcnTextBox::new(""),
+            personal_number_input: PersonalNumberTextBox::new(""),
+            document_type: DocumentType::default(),
             id_card_number_input: IdCardNumberTextBox::new(""),
+            id_card_nationality_input: CountryCodeTextBox::new(DEFAULT_COUNTRY_CODE),
+            id_card_issuing_country_input: CountryCodeTextBox::new(DEFAULT_COUNTRY_CODE),
             id_card_issue_authority_input: TextBox::new("", 25),
             id_card_issue_date_input: DateInput::new(
                 "Issue Date",
@@ -109,58 +149,324 @@ impl AddGuestScreen {
                 Date::today(),
                 AppMessage::AddGuestMessage(AddGuestMessage::ToggleShowDateOfBirth),
             ),
+            date_of_birth_entered: false,
+            phone_number_edited: false,
             focus_chain: FocusChain::new(FOCUS_IDS_WITHOUT_CARD.into()),
+            editing: None,
+            duplicates: None,
+            checked_duplicate_queries: vec![],
         }
     }
 
-    fn view_card_input(&self) -> Element<AppMessage> {
+    pub fn edit(guest: &Guest) -> Self {
+        let mut screen = Self::new();
+        screen.first_name_input.update(guest.first_name.clone());
+        screen.last_name_input.update(guest.last_name.clone());
+        screen.date_of_birth_input.update_date(guest.date_of_birth);
+        screen.date_of_birth_entered = true;
+        screen.phone_number_input.update(
+            guest
+                .phone_number
+                .as_deref()
+                .map(format_phone_number)
+                .unwrap_or_default(),
+        );
+        if let Some(id_card) = &guest.id_card {
+            screen.has_id_card_checkbox.update(true);
+            screen.document_type = id_card.document_type;
+            screen.id_card_ucn_input.update(id_card.ucn.clone());
+            screen.personal_number_input.update(id_card.ucn.clone());
+            screen
+                .id_card_number_input
+                .update(id_card.id_card_number.clone());
+            screen
+                .id_card_nationality_input
+                .update(id_card.nationality.clone());
+            screen
+                .id_card_issuing_country_input
+                .update(id_card.issuing_country.clone());
+            screen
+                .id_card_issue_authority_input
+                .update(id_card.issue_authority.clone());
+            screen
+                .id_card_issue_date_input
+                .update_date(id_card.issue_date);
+            screen
+                .id_card_validity_input
+                .update_date(id_card.validity_date);
+        }
+        screen.editing = Some(guest.clone());
+        screen.update_focus_chain();
+
+        screen
+    }
+
+    fn update_focus_chain(&mut self) {
+        let selected = self.focus_chain.get_selected();
+        let mut ids = FOCUS_IDS_WITHOUT_CARD.to_vec();
         if self.has_id_card_checkbox.is_checked() {
-            column![
-                text_input("UCN", self.id_card_ucn_input.get_text())
-                    .id(ID_CARD_UCN_ID)
-                    .on_input(|x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeUcn(x)))
-                    .on_submit(AppMessage::AddGuestMessage(AddGuestMessage::AddGuest))
-                    .align_x(Center)
-                    .width(TEXT_BOX_WIDTH)
-                    .line_height(1.5),
-                text_input("Id Card Number", self.id_card_number_input.get_text())
-                    .id(ID_CARD_NUMBER_ID)
-                    .on_input(
-                        |x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeIdCardNumber(x))
-                    )
-                    .on_submit(AppMessage::AddGuestMessage(AddGuestMessage::AddGuest))
-                    .align_x(Center)
-                    .width(TEXT_BOX_WIDTH)
-                    .line_height(1.5),
-                text_input(
-                    "Id Card Issue Authority",
-                    self.id_card_issue_authority_input.get_text()
-                )
-                .id(ID_CARD_ISSUE_AUTHORITY_ID)
-                .on_input(|x| AppMessage::AddGuestMessage(
-                    AddGuestMessage::ChangeIdCardIssueAuthority(x)
-                ))
+            ids.push(ID_CARD_UCN_ID);
+            ids.push(ID_CARD_NUMBER_ID);
+            if self.document_type != DocumentType::NationalIdCard {
+                ids.push(ID_CARD_NATIONALITY_ID);
+            }
+            ids.push(ID_CARD_ISSUING_COUNTRY_ID);
+            ids.push(ID_CARD_ISSUE_AUTHORITY_ID);
+        }
+
+        self.focus_chain = FocusChain::new(ids);
+        self.focus_chain.set_focus(selected);
+    }
+
+    // Returns false if the input doesn't accept the value, the input is left empty then
+    fn apply_mrz_value<T>(input: &mut T, value: &str) -> bool
+    where
+        T: TextElement,
+    {
+        input.update("");
+        input.update(value);
+        if input.get_text() != value {
+            input.update("");
+            return false;
+        }
+        true
+    }
+
+    fn apply_mrz(&mut self) {
+        let mrz = match Mrz::parse(self.mrz_input.get_text()) {
+            Ok(ok) => ok,
+            Err(err) => {
+                self.error = err;
+                return;
+            }
+        };
+        let truncate = |name: String| name.chars().take(NAME_MAX_LENGTH).collect::<String>();
+
+        self.mrz_input.update("");
+        self.first_name_input.update(truncate(mrz.first_name));
+        self.last_name_input.update(truncate(mrz.last_name));
+        if let Some(date_of_birth) = mrz.date_of_birth {
+            self.date_of_birth_input.update_date(date_of_birth);
+            self.date_of_birth_entered = true;
+        }
+        self.has_id_card_checkbox.update(true);
+        self.document_type = mrz.document_type;
+
+        let mut rejected = vec![];
+        if !Self::apply_mrz_value(&mut self.id_card_number_input, &mrz.document_number) {
+            rejected.push("document number");
+        }
+        if !Self::apply_mrz_value(&mut self.id_card_nationality_input, &mrz.nationality) {
+            rejected.push("nationality");
+        }
+        if !Self::apply_mrz_value(
+            &mut self.id_card_issuing_country_input,
+            &mrz.issuing_country,
+        ) {
+            rejected.push("issuing country");
+        }
+        self.id_card_ucn_input.update("");
+        self.personal_number_input.update("");
+        let personal_number_applied = if self.has_bulgarian_ucn() {
+            Self::apply_mrz_value(&mut self.id_card_ucn_input, &mrz.personal_number)
+        } else {
+            Self::apply_mrz_value(&mut self.personal_number_input, &mrz.personal_number)
+        };
+        if !personal_number_applied {
+            rejected.push("personal number");
+        }
+        if let Some(expiry_date) = mrz.expiry_date {
+            self.id_card_validity_input.update_date(expiry_date);
+        }
+        self.update_focus_chain();
+
+        let mut errors = vec![];
+        if !mrz.failed_checks.is_empty() {
+            let fields: Vec<_> = mrz.failed_checks.iter().map(|x| x.to_string()).collect();
+            errors.push(format!(
+                "Check digit failed for {}, verify it",
+                fields.join(", ")
+            ));
+        }
+        if !rejected.is_empty() {
+            errors.push(format!(
+                "Could not apply {}, enter it manually",
+                rejected.join(", ")
+            ));
+        }
+        self.error = errors.join(". ");
+        self.mrz_failed_checks = mrz.failed_checks;
+    }
+
+    fn is_highlighted(&self, field: MrzField) -> bool {
+        // A failed composite check alone points at data without its own check digit,
+        // like the TD1 personal number
+        self.mrz_failed_checks.contains(&field)
+            || (field == MrzField::PersonalNumber
+                && self.mrz_failed_checks == [MrzField::Composite])
+    }
+
+    fn get_input_style(
+        &self,
+        field: MrzField,
+    ) -> impl Fn(&Theme, text_input::Status) -> text_input::Style {
+        let highlight = self.is_highlighted(field);
+
+        move |theme, status| {
+            let mut style = text_input::default(theme, status);
+            if highlight {
+                style.border.color = ERROR_COLOR;
+                style.border.width = 2.0;
+            }
+            style
+        }
+    }
+
+    fn get_nationality(&self) -> &str {
+        match self.document_type {
+            DocumentType::NationalIdCard => self.id_card_issuing_country_input.get_text(),
+            _ => self.id_card_nationality_input.get_text(),
+        }
+    }
+
+    fn has_bulgarian_ucn(&self) -> bool {
+        self.get_nationality() == DEFAULT_COUNTRY_CODE
+    }
+
+    fn get_personal_number(&self) -> &str {
+        if self.has_bulgarian_ucn() {
+            self.id_card_ucn_input.get_text()
+        } else {
+            self.personal_number_input.get_text()
+        }
+    }
+
+    fn view_card_input(&self) -> Element<AppMessage> {
+        if !self.has_id_card_checkbox.is_checked() {
+            return column![].into();
+        }
+
+        let is_national_id = self.document_type == DocumentType::NationalIdCard;
+        let ucn_placeholder = match (self.has_bulgarian_ucn(), is_national_id) {
+            (true, true) => "UCN",
+            (true, false) => "UCN (optional)",
+            (false, _) => "Personal number (optional)",
+        };
+        let issue_authority_placeholder = if is_national_id {
+            "Issue Authority"
+        } else {
+            "Issue Authority (optional)"
+        };
+        let nationality_input = (!is_national_id).then(|| {
+            text_input(
+                "Nationality (e.g. DEU)",
+                self.id_card_nationality_input.get_text(),
+            )
+            .id(ID_CARD_NATIONALITY_ID)
+            .on_input(|x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeNationality(x)))
+            .on_submit(AppMessage::AddGuestMessage(AddGuestMessage::AddGuest))
+            .align_x(Center)
+            .width(TEXT_BOX_WIDTH)
+            .line_height(1.5)
+        });
+
+        column![
+            pick_list(DocumentType::ALL, Some(self.document_type), |x| {
+                AppMessage::AddGuestMessage(AddGuestMessage::ChangeDocumentType(x))
+            }),
+            text_input(ucn_placeholder, self.get_personal_number())
+                .id(ID_CARD_UCN_ID)
+                .style(self.get_input_style(MrzField::PersonalNumber))
+                .on_input(|x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeUcn(x)))
                 .on_submit(AppMessage::AddGuestMessage(AddGuestMessage::AddGuest))
                 .align_x(Center)
                 .width(TEXT_BOX_WIDTH)
                 .line_height(1.5),
-                self.id_card_issue_date_input
-                    .view(
-                        |x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeIdCardIssueDate(x))
-                    ),
-                self.id_card_validity_input
-                    .view(|x| AppMessage::AddGuestMessage(
-                        AddGuestMessage::ChangeIdCardValidityDate(x)
-                    ))
-            ]
-            .spacing(FORM_SPACING)
+            self.view_ucn_hint(),
+            text_input("Document Number", self.id_card_number_input.get_text())
+                .id(ID_CARD_NUMBER_ID)
+                .style(self.get_input_style(MrzField::DocumentNumber))
+                .on_input(|x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeIdCardNumber(x)))
+                .on_submit(AppMessage::AddGuestMessage(AddGuestMessage::AddGuest))
+                .align_x(Center)
+                .width(TEXT_BOX_WIDTH)
+                .line_height(1.5),
+        ]
+        .push_maybe(nationality_input)
+        .push(
+            text_input(
+                "Issuing country (e.g. BGR)",
+                self.id_card_issuing_country_input.get_text(),
+            )
+            .id(ID_CARD_ISSUING_COUNTRY_ID)
+            .on_input(|x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeIssuingCountry(x)))
+            .on_submit(AppMessage::AddGuestMessage(AddGuestMessage::AddGuest))
             .align_x(Center)
-        } else {
-            column![]
+            .width(TEXT_BOX_WIDTH)
+            .line_height(1.5),
+        )
+        .push(
+            text_input(
+                issue_authority_placeholder,
+                self.id_card_issue_authority_input.get_text(),
+            )
+            .id(ID_CARD_ISSUE_AUTHORITY_ID)
+            .on_input(|x| {
+                AppMessage::AddGuestMessage(AddGuestMessage::ChangeIdCardIssueAuthority(x))
+            })
+            .on_submit(AppMessage::AddGuestMessage(AddGuestMessage::AddGuest))
+            .align_x(Center)
+            .width(TEXT_BOX_WIDTH)
+            .line_height(1.5),
+        )
+        .push(
+            self.id_card_issue_date_input
+                .view(|x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeIdCardIssueDate(x))),
+        )
+        .push(self.id_card_validity_input.view_highlighted(
+            |x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeIdCardValidityDate(x)),
+            self.is_highlighted(MrzField::ExpiryDate),
+        ))
+        .spacing(FORM_SPACING)
+        .align_x(Center)
+        .into()
+    }
+
+    fn view_ucn_hint(&self) -> Element<'_, AppMessage> {
+        let ucn = self.id_card_ucn_input.get_text();
+        if ucn.len() < 10 || !self.has_bulgarian_ucn() {
+            return column![].into();
         }
+
+        match Ucn::parse(ucn) {
+            Ok(ucn) => text!("{}, born {}", ucn.gender, ucn.date_of_birth),
+            Err(err) => text(err).color(ERROR_COLOR),
+        }
+        .size(14)
         .into()
     }
 
+    fn apply_ucn(&mut self) {
+        if !self.has_bulgarian_ucn() {
+            return;
+        }
+        let Ok(ucn) = Ucn::parse(self.id_card_ucn_input.get_text()) else {
+            return;
+        };
+        let date_of_birth = self.date_of_birth_input.get_date();
+
+        if !self.date_of_birth_entered {
+            self.date_of_birth_input.update_date(ucn.date_of_birth);
+        } else if !is_same_date(date_of_birth, ucn.date_of_birth) {
+            self.error = format!(
+                "UCN date of birth {} doesn't match {date_of_birth}",
+                ucn.date_of_birth
+            );
+        } else {
+            self.error = "".to_owned();
+        }
+    }
+
     fn validate_date_before(date: Date, max_date: Date, message: &str) -> Result<Date, String> {
         if date.year > max_date.year
             || (date.year == max_date.year && date.month > max_date.month)
@@ -173,22 +479,55 @@ impl AddGuestScreen {
         Ok(date)
     }
 
+    fn validate_country_code(code: &str, message: &str) -> Result<String, String> {
+        if code.len() != 3 {
+            return Err(message.to_owned());
+        }
+        Ok(code.to_owned())
+    }
+
+    fn retrieve_and_validate_ucn(&self) -> Result<String, String> {
+        let ucn = self.get_personal_number();
+        if ucn.is_empty() {
+            return match self.document_type {
+                DocumentType::NationalIdCard if self.has_bulgarian_ucn() => {
+                    Err("Enter UCN".to_owned())
+                }
+                _ => Ok("".to_owned()),
+            };
+        }
+
+        if self.has_bulgarian_ucn() {
+            let parsed = Ucn::parse(ucn)?;
+            if !is_same_date(parsed.date_of_birth, self.date_of_birth_input.get_date()) {
+                return Err("UCN doesn't match date of birth".to_owned());
+            }
+        }
+        Ok(ucn.to_owned())
+    }
+
     fn retrieve_and_validate_card(&self) -> Result<IdCard, String> {
-        let ucn = if self.id_card_ucn_input.get_text().len() < 10 {
-            return Err("Invalid UCN".to_owned());
-        } else {
-            self.id_card_ucn_input.get_text().to_owned()
+        let document_type = self.document_type;
+        let issuing_country = Self::validate_country_code(
+            self.id_card_issuing_country_input.get_text(),
+            "Invalid issuing country",
+        )?;
+        let nationality =
+            Self::validate_country_code(self.get_nationality(), "Invalid nationality")?;
+        let ucn = self.retrieve_and_validate_ucn()?;
+        let min_number_length = match document_type {
+            DocumentType::NationalIdCard | DocumentType::ResidencePermit => 9,
+            DocumentType::Passport => 6,
         };
-        let id_card_number = if self.id_card_number_input.get_text().len() < 9 {
-            return Err("Invalid card number".to_owned());
+        let id_card_number = if self.id_card_number_input.get_text().len() < min_number_length {
+            return Err("Invalid document number".to_owned());
         } else {
             self.id_card_number_input.get_text().to_owned()
         };
-        let issue_authority = if self.id_card_issue_authority_input.get_text().is_empty() {
+        let issue_authority = self.id_card_issue_authority_input.get_text().to_owned();
+        if document_type == DocumentType::NationalIdCard && issue_authority.is_empty() {
             return Err("Invalid issue authority".to_owned());
-        } else {
-            self.id_card_issue_authority_input.get_text().to_owned()
-        };
+        }
         let today = Date::today();
         let issue_date = Self::validate_date_before(
             self.id_card_issue_date_input.get_date(),
@@ -203,23 +542,32 @@ impl AddGuestScreen {
         let validity_date = self.id_card_validity_input.get_date();
 
         Ok(IdCard {
+            document_type,
             ucn,
             id_card_number,
+            nationality,
+            issuing_country,
             issue_authority,
             issue_date,
             validity_date,
         })
     }
 
-    fn retrieve_and_validate_phone(&self) -> Result<Option<String>, String> {
-        let phone = self.phone_number_input.get_text();
-        if phone.is_empty() {
-            Ok(None)
-        } else if phone.len() < 12 {
-            Err("Invalid phone number".to_owned())
-        } else {
-            Ok(Some(phone.to_owned()))
-        }
+    fn get_unchanged_card(&self) -> Option<IdCard> {
+        let id_card = self.editing.as_ref()?.id_card.as_ref()?;
+        let is_unchanged = self.document_type == id_card.document_type
+            && self.get_personal_number() == id_card.ucn
+            && self.id_card_number_input.get_text() == id_card.id_card_number
+            && self.get_nationality() == id_card.nationality
+            && self.id_card_issuing_country_input.get_text() == id_card.issuing_country
+            && self.id_card_issue_authority_input.get_text() == id_card.issue_authority
+            && is_same_date(self.id_card_issue_date_input.get_date(), id_card.issue_date)
+            && is_same_date(
+                self.id_card_validity_input.get_date(),
+                id_card.validity_date,
+            );
+
+        is_unchanged.then(|| id_card.clone())
     }
 
     fn retrieve_and_validate_input(&self) -> Result<AddGuestInput, String> {
@@ -244,11 +592,19 @@ impl AddGuestScreen {
             "Invalid date of birth",
         )?;
         let id_card = if self.has_id_card_checkbox.is_checked() {
-            Some(self.retrieve_and_validate_card()?)
+            // An unchanged card is kept as is, even if it has expired since
+            match self.get_unchanged_card() {
+                Some(id_card) => Some(id_card),
+                None => Some(self.retrieve_and_validate_card()?),
+            }
         } else {
             None
         };
-        let phone_number = self.retrieve_and_validate_phone()?;
+        // The form shows stored numbers formatted, so an untouched one is kept as stored
+        let phone_number = match &self.editing {
+            Some(guest) if !self.phone_number_edited => guest.phone_number.clone(),
+            _ => self.phone_number_input.get_normalized()?,
+        };
 
         Ok(AddGuestInput::new(
             first_name.to_owned(),
@@ -259,6 +615,163 @@ impl AddGuestScreen {
         ))
     }
 
+    fn get_duplicate_queries(&self, input: &AddGuestInput) -> Vec<FindGuestInput> {
+        let mut queries = vec![FindGuestInput {
+            first_name: Some(input.first_name.clone()),
+            last_name: Some(input.last_name.clone()),
+            date_of_birth: Some(input.date_of_birth.clone()),
+            ucn: None,
+            phone_number: None,
+            ..Default::default()
+        }];
+        let ucn = self.get_personal_number();
+        if self.has_id_card_checkbox.is_checked() && !ucn.is_empty() {
+            queries.push(FindGuestInput {
+                first_name: None,
+                last_name: None,
+                date_of_birth: None,
+                ucn: Some(ucn.to_owned()),
+                phone_number: None,
+                ..Default::default()
+            });
+        }
+        if let Some(phone_number) = &input.phone_number {
+            queries.push(FindGuestInput {
+                first_name: None,
+                last_name: None,
+                date_of_birth: None,
+                ucn: None,
+                phone_number: Some(phone_number.clone()),
+                ..Default::default()
+            });
+        }
+
+        queries
+    }
+
+    fn check_duplicates(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.duplicates = None;
+        let queries = match self.retrieve_and_validate_input() {
+            Ok(input) => self.get_duplicate_queries(&input),
+            Err(err) => {
+                self.error = err;
+                return Task::none();
+            }
+        };
+
+        self.checked_duplicate_queries = queries.clone();
+
+        Task::perform(
+            find_guests_matching_any(ApiClient::new(&global_state), queries),
+            |res| match res {
+                ApiResult::Success(ids) => {
+                    AppMessage::AddGuestMessage(AddGuestMessage::DuplicatesFound(ids))
+                }
+                ApiResult::Forbidden => AppMessage::TokenExpired,
+                ApiResult::BadRequest(err) => {
+                    AppMessage::AddGuestMessage(AddGuestMessage::ShowError(err))
+                }
+                ApiResult::Error(err) => {
+                    println!("Error checking for duplicate guests: {err}");
+                    show_notification("Unexpected error", NotificationType::Error)
+                }
+            },
+        )
+    }
+
+    fn load_duplicates(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        ids: Vec<Uuid>,
+    ) -> Task<AppMessage> {
+        if ids.is_empty() {
+            return self.add_guest(global_state);
+        }
+        self.duplicates = Some(vec![]);
+
+        Task::batch(ids.into_iter().map(|id| {
+            Task::perform(
+                get_guest(ApiClient::new(&global_state), id),
+                |res| match res {
+                    ApiResult::Success(guest) => AppMessage::AddGuestMessage(
+                        AddGuestMessage::DuplicateLoaded(Box::new(guest)),
+                    ),
+                    ApiResult::Forbidden => AppMessage::TokenExpired,
+                    ApiResult::BadRequest(err) => {
+                        AppMessage::AddGuestMessage(AddGuestMessage::ShowError(err))
+                    }
+                    ApiResult::Error(err) => {
+                        println!("Error fetching guest: {err}");
+                        show_notification("Unexpected error", NotificationType::Error)
+                    }
+                },
+            )
+        }))
+    }
+
+    fn confirm_create_guest(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        // The form may have changed since the duplicates were shown, then they are checked again
+        let is_unchanged = self.retrieve_and_validate_input().is_ok_and(|input| {
+            self.get_duplicate_queries(&input) == self.checked_duplicate_queries
+        });
+        if !is_unchanged {
+            return self.check_duplicates(global_state);
+        }
+
+        self.duplicates = None;
+        self.add_guest(global_state)
+    }
+
+    fn view_duplicate(guest: &Guest) -> Element<'_, AppMessage> {
+        let phone_number = guest
+            .phone_number
+            .as_deref()
+            .map_or("no phone".to_owned(), format_phone_number);
+        let ucn = guest.id_card.as_ref().map_or("no UCN", |card| &card.ucn);
+
+        row![
+            text!(
+                "{} {}, {}, {phone_number}, {ucn}",
+                guest.first_name,
+                guest.last_name,
+                guest.date_of_birth
+            )
+            .width(360),
+            button("Use this guest").on_press(AppMessage::AddGuestMessage(
+                AddGuestMessage::UseExistingGuest(guest.id)
+            )),
+        ]
+        .spacing(10)
+        .align_y(Center)
+        .into()
+    }
+
+    fn view_duplicates(&self) -> Option<Element<'_, AppMessage>> {
+        let duplicates = self.duplicates.as_ref()?;
+
+        Some(
+            container(
+                column![
+                    text("Possible duplicates"),
+                    Column::with_children(duplicates.iter().map(Self::view_duplicate)).spacing(5),
+                    row![
+                        button("Create new guest").on_press(AppMessage::AddGuestMessage(
+                            AddGuestMessage::ConfirmCreateGuest
+                        )),
+                        button("Cancel").on_press(AppMessage::AddGuestMessage(
+                            AddGuestMessage::CancelCreateGuest
+                        )),
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(10)
+                .align_x(Center),
+            )
+            .width(520)
+            .into(),
+        )
+    }
+
     fn add_guest(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
         let raw_input = self.retrieve_and_validate_input();
         let input = if let Ok(ok) = raw_input {
@@ -269,16 +782,52 @@ impl AddGuestScreen {
         };
 
         Task::perform(
-            services::add_guest::add_guest(global_state, input),
+            services::add_guest::add_guest(ApiClient::new(&global_state), input),
             move |res| match res {
-                Ok(AddGuestResult::GuestAdded(uuid)) => {
+                ApiResult::Success(uuid) => {
                     AppMessage::AddGuestMessage(AddGuestMessage::GuestAdded(uuid))
                 }
-                Ok(AddGuestResult::Forbidden) => AppMessage::TokenExpired,
-                Ok(AddGuestResult::BadRequest(bad_request)) => {
+                ApiResult::Forbidden => AppMessage::TokenExpired,
+                ApiResult::BadRequest(bad_request) => {
                     AppMessage::AddGuestMessage(AddGuestMessage::ShowError(bad_request))
                 }
-                Err(err) => AppMessage::AddGuestMessage(AddGuestMessage::ShowError(err)),
+                ApiResult::Error(err) => {
+                    AppMessage::AddGuestMessage(AddGuestMessage::ShowError(err))
+                }
+            },
+        )
+    }
+
+    fn update_guest(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        let Some(guest) = &self.editing else {
+            return Task::none();
+        };
+        let input = match self.retrieve_and_validate_input() {
+            Ok(ok) => UpdateGuestInput::from_changes(guest, ok),
+            Err(err) => {
+                self.error = err;
+                return Task::none();
+            }
+        };
+        if input.is_empty() {
+            return Task::done(show_notification(
+                "No changes to save",
+                NotificationType::Information,
+            ));
+        }
+
+        Task::perform(
+            update_guest(ApiClient::new(&global_state), guest.id, input),
+            |res| match res {
+                ApiResult::Success(_) => AppMessage::AddGuestMessage(AddGuestMessage::GuestUpdated),
+                ApiResult::Forbidden => AppMessage::TokenExpired,
+                ApiResult::BadRequest(err) => {
+                    AppMessage::AddGuestMessage(AddGuestMessage::ShowError(err))
+                }
+                ApiResult::Error(err) => {
+                    println!("Error updating guest: {err}");
+                    show_notification("Unexpected error", NotificationType::Error)
+                }
             },
         )
     }
@@ -286,19 +835,33 @@ impl AddGuestScreen {
     fn clear_inputs(&mut self) {
         let today = Date::today();
 
+        self.mrz_input.update("");
+        self.mrz_failed_checks.clear();
         self.first_name_input.update("");
         self.last_name_input.update("");
         self.id_card_issue_authority_input.update("");
         self.id_card_number_input.update("");
         self.id_card_ucn_input.update("");
+        self.personal_number_input.update("");
+        self.id_card_nationality_input.update(DEFAULT_COUNTRY_CODE);
+        self.id_card_issuing_country_input
+            .update(DEFAULT_COUNTRY_CODE);
+        self.document_type = DocumentType::default();
+        self.update_focus_chain();
         self.phone_number_input.update("");
+        self.phone_number_edited = false;
         self.date_of_birth_input.update_date(today);
+        self.date_of_birth_entered = false;
         self.id_card_issue_date_input.update_date(today);
         self.id_card_validity_input.update_date(today);
         self.error = "".to_owned();
     }
 }
 impl Screen for AddGuestScreen {
+    fn keep_alive(&self) -> bool {
+        true
+    }
+
     fn update(
         &mut self,
         message: AppMessage,
@@ -317,30 +880,72 @@ impl Screen for AddGuestScreen {
                     Task::none()
                 }
                 AddGuestMessage::ChangeCheckbox(x) => {
-                    let selected = self.focus_chain.get_selected();
-                    self.focus_chain = if x {
-                        FocusChain::new(FOCUS_IDS_WITH_CARD.into())
-                    } else {
-                        FocusChain::new(FOCUS_IDS_WITHOUT_CARD.into())
-                    };
-                    self.focus_chain.set_focus(selected);
-
                     self.has_id_card_checkbox.update(x);
+                    self.update_focus_chain();
+                    Task::none()
+                }
+                AddGuestMessage::ChangeDocumentType(document_type) => {
+                    self.document_type = document_type;
+                    self.update_focus_chain();
+                    Task::none()
+                }
+                AddGuestMessage::ChangeNationality(x) => {
+                    self.focus_chain.set_focus(Some(ID_CARD_NATIONALITY_ID));
+                    self.id_card_nationality_input.update(x);
+                    Task::none()
+                }
+                AddGuestMessage::ChangeIssuingCountry(x) => {
+                    self.focus_chain.set_focus(Some(ID_CARD_ISSUING_COUNTRY_ID));
+                    self.id_card_issuing_country_input.update(x);
                     Task::none()
                 }
                 AddGuestMessage::ChangePhoneNumber(x) => {
                     self.focus_chain.set_focus(Some(PHONE_NUMBER_ID));
                     self.phone_number_input.update(x);
+                    self.phone_number_edited = true;
+                    Task::none()
+                }
+                AddGuestMessage::ChangePhoneCountry(country) => {
+                    self.phone_number_input.set_country(country);
+                    self.phone_number_edited = true;
+                    Task::none()
+                }
+                AddGuestMessage::ChangeMrz(x) => {
+                    self.focus_chain.set_focus(Some(MRZ_ID));
+                    self.mrz_input.update(x);
+                    if Mrz::is_complete(self.mrz_input.get_text()) {
+                        self.apply_mrz();
+                    }
+                    Task::none()
+                }
+                AddGuestMessage::MrzLineEnd => {
+                    // Scanners press enter after every line, so keep the lines apart
+                    let mrz = self.mrz_input.get_text();
+                    if Mrz::is_complete(mrz) {
+                        self.apply_mrz();
+                    } else if !mrz.is_empty() && !mrz.ends_with(' ') {
+                        self.mrz_input.update(format!("{mrz} "));
+                    }
                     Task::none()
                 }
                 AddGuestMessage::ChangeUcn(x) => {
                     self.focus_chain.set_focus(Some(ID_CARD_UCN_ID));
-                    self.id_card_ucn_input.update(x);
+                    if self.has_bulgarian_ucn() {
+                        self.id_card_ucn_input.update(x);
+                    } else {
+                        self.personal_number_input.update(x);
+                    }
+                    self.mrz_failed_checks.retain(|field| {
+                        *field != MrzField::PersonalNumber && *field != MrzField::Composite
+                    });
+                    self.apply_ucn();
                     Task::none()
                 }
                 AddGuestMessage::ChangeIdCardNumber(x) => {
                     self.focus_chain.set_focus(Some(ID_CARD_NUMBER_ID));
                     self.id_card_number_input.update(x);
+                    self.mrz_failed_checks
+                        .retain(|field| *field != MrzField::DocumentNumber);
                     Task::none()
                 }
                 AddGuestMessage::ChangeIdCardIssueAuthority(x) => {
@@ -364,6 +969,8 @@ impl Screen for AddGuestScreen {
                 AddGuestMessage::ChangeIdCardValidityDate(date) => {
                     self.id_card_validity_input.update_date(date);
                     self.id_card_validity_input.toggle_show();
+                    self.mrz_failed_checks
+                        .retain(|field| *field != MrzField::ExpiryDate);
                     Task::none()
                 }
                 AddGuestMessage::ToggleShowDateOfBirth => {
@@ -372,18 +979,63 @@ impl Screen for AddGuestScreen {
                 }
                 AddGuestMessage::ChangeDateOfBirth(date) => {
                     self.date_of_birth_input.update_date(date);
+                    self.date_of_birth_entered = true;
                     self.date_of_birth_input.toggle_show();
+                    self.mrz_failed_checks
+                        .retain(|field| *field != MrzField::DateOfBirth);
+                    self.apply_ucn();
                     Task::none()
                 }
-                AddGuestMessage::AddGuest => self.add_guest(global_state),
+                AddGuestMessage::AddGuest => match self.editing {
+                    Some(_) => self.update_guest(global_state),
+                    None => self.check_duplicates(global_state),
+                },
                 AddGuestMessage::ShowError(err) => {
                     self.error = err;
                     Task::none()
                 }
-                AddGuestMessage::GuestAdded(_uuid) => {
+                AddGuestMessage::GuestAdded(uuid) => {
+                    global_state.lock().unwrap().add_recent_guest(uuid);
                     self.clear_inputs();
                     Task::done(show_notification("Guest added", NotificationType::Success))
                 }
+                AddGuestMessage::DuplicatesFound(ids) => self.load_duplicates(global_state, ids),
+                AddGuestMessage::DuplicateLoaded(guest) => {
+                    if let Some(duplicates) = &mut self.duplicates {
+                        duplicates.retain(|loaded| loaded.id != guest.id);
+                        duplicates.push(*guest);
+                    }
+                    Task::none()
+                }
+                AddGuestMessage::UseExistingGuest(guest_id) => {
+                    let Some(guest) = self
+                        .duplicates
+                        .as_ref()
+                        .and_then(|duplicates| duplicates.iter().find(|x| x.id == guest_id))
+                        .cloned()
+                    else {
+                        return Task::none();
+                    };
+                    global_state.lock().unwrap().add_recent_guest(guest_id);
+                    self.duplicates = None;
+                    self.clear_inputs();
+                    Task::done(AppMessage::NavigateTo(ScreenType::EditGuest(Box::new(
+                        guest,
+                    ))))
+                }
+                AddGuestMessage::ConfirmCreateGuest => self.confirm_create_guest(global_state),
+                AddGuestMessage::CancelCreateGuest => {
+                    self.duplicates = None;
+                    Task::none()
+                }
+                AddGuestMessage::GuestUpdated => {
+                    self.error = "".to_owned();
+                    Task::done(show_notification(
+                        "Guest updated",
+                        NotificationType::Success,
+                    ))
+                    .chain(Task::done(AppMessage::NavigateBack))
+                }
             },
             AppMessage::SelectNext => {
                 self.focus_chain.set_next();
@@ -398,12 +1050,24 @@ impl Screen for AddGuestScreen {
     }
 
     fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<AppMessage> {
+        let (title, submit_text) = match self.editing {
+            Some(_) => ("Edit Guest", "Save"),
+            None => ("Add Guest", "Add"),
+        };
+
         scrollable(
             column![
-                text!("Add Guest")
+                text!("{title}")
                     .align_x(Center)
                     .size(TITLE_FONT_SIZE)
                     .width(Fill),
+                text_input("Scan or paste MRZ", self.mrz_input.get_text())
+                    .id(MRZ_ID)
+                    .on_input(|x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeMrz(x)))
+                    .on_submit(AppMessage::AddGuestMessage(AddGuestMessage::MrzLineEnd))
+                    .align_x(Center)
+                    .width(TEXT_BOX_WIDTH)
+                    .line_height(1.5),
                 text_input("First Name", self.first_name_input.get_text())
                     .id(FIRST_NAME_ID)
                     .on_input(|x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeFirstName(x)))
@@ -418,17 +1082,28 @@ impl Screen for AddGuestScreen {
                     .align_x(Center)
                     .width(TEXT_BOX_WIDTH)
                     .line_height(1.5),
-                self.date_of_birth_input
-                    .view(|x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeDateOfBirth(x))),
-                text_input("Phone number (with +)", self.phone_number_input.get_text())
-                    .id(PHONE_NUMBER_ID)
-                    .on_input(
-                        |x| AppMessage::AddGuestMessage(AddGuestMessage::ChangePhoneNumber(x))
-                    )
-                    .on_submit(AppMessage::AddGuestMessage(AddGuestMessage::AddGuest))
-                    .align_x(Center)
-                    .width(TEXT_BOX_WIDTH)
-                    .line_height(1.5),
+                self.date_of_birth_input.view_highlighted(
+                    |x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeDateOfBirth(x)),
+                    self.is_highlighted(MrzField::DateOfBirth),
+                ),
+                row![
+                    pick_list(
+                        COUNTRIES,
+                        Some(self.phone_number_input.get_country()),
+                        |x| { AppMessage::AddGuestMessage(AddGuestMessage::ChangePhoneCountry(x)) }
+                    ),
+                    text_input("Phone number", self.phone_number_input.get_text())
+                        .id(PHONE_NUMBER_ID)
+                        .on_input(|x| AppMessage::AddGuestMessage(
+                            AddGuestMessage::ChangePhoneNumber(x)
+                        ))
+                        .on_submit(AppMessage::AddGuestMessage(AddGuestMessage::AddGuest))
+                        .align_x(Center)
+                        .width(TEXT_BOX_WIDTH)
+                        .line_height(1.5),
+                ]
+                .spacing(10)
+                .align_y(Center),
                 self.has_id_card_checkbox
                     .view(|x| AppMessage::AddGuestMessage(AddGuestMessage::ChangeCheckbox(x))),
                 self.view_card_input(),
@@ -437,11 +1112,12 @@ impl Screen for AddGuestScreen {
                     .size(18)
                     .align_x(Center)
                     .width(Fill),
-                button("Add")
+                button(submit_text)
                     .on_press(AppMessage::AddGuestMessage(AddGuestMessage::AddGuest))
                     .height(30)
                     .width(80)
             ]
+            .push_maybe(self.view_duplicates())
             .spacing(FORM_SPACING)
             .align_x(Center)
             .padding(FORM_PADDING),
@@ -13,7 +13,7 @@ use iced::{
 use uuid::Uuid;
 
 use crate::{
-    app::{AppMessage, GlobalState, Screen},
+    app::{AppMessage, GlobalState, Screen, ScreenType},
     components::{
         combo_box::{
             bathroom_type_combo_box::BathroomTypeComboBox, bed_size_combo_box::BedSizeComboBox,
@@ -29,10 +29,13 @@ use crate::{
     model::{
         bathroom_type::BathroomType,
         bed::{Bed, BedSize},
+        room::Room,
     },
     services::{
         self,
-        add_room::{AddRoomInput, AddRoomResult},
+        add_room::AddRoomInput,
+        api_client::{ApiClient, ApiResult},
+        update_room::update_room,
     },
     styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TEXT_BOX_WIDTH, TITLE_FONT_SIZE},
     utils::show_notification,
@@ -50,6 +53,7 @@ pub enum AddRoomMessage {
     AddBedSizeInput,
     AddRoom,
     RoomAdded(Uuid),
+    RoomUpdated,
     ShowError(String),
 }
 
@@ -66,6 +70,7 @@ pub struct AddRoomScreen {
     bed_count_inputs: BTreeMap<u64, BedCountInput>,
     error: String,
     focus_chain: FocusChain,
+    editing: Option<Uuid>,
 }
 impl AddRoomScreen {
     pub fn new() -> Self {
@@ -78,6 +83,67 @@ impl AddRoomScreen {
             bed_count_inputs: BTreeMap::new(),
             error: "".to_owned(),
             focus_chain: FocusChain::new(vec![ROOM_NUMBER_ID, FLOOR_ID, PRICE_ID]),
+            editing: None,
+        }
+    }
+
+    pub fn edit(room: &Room) -> Self {
+        let mut screen = Self::new();
+        screen.editing = Some(room.id);
+        screen
+            .price
+            .update(format!("{:.2}", room.price as f64 / 100.0));
+        screen.floor.update(room.floor.to_string());
+        screen.room_number.update(room.room_number.clone());
+        screen.bathroom_type_combo_box.update(room.bathroom_type);
+        for bed in &room.beds {
+            let mut input = BedCountInput::new(screen.id_counter);
+            input.bed_size.update(bed.bed_size);
+            input.count.update(bed.count.to_string());
+            screen.bed_count_inputs.insert(screen.id_counter, input);
+            screen.id_counter += 1;
+        }
+
+        screen
+    }
+
+    fn submit(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        let input = match self.get_input() {
+            Ok(ok) => ok,
+            Err(err) => {
+                self.error = err;
+                return Task::none();
+            }
+        };
+        let client = ApiClient::new(&global_state);
+
+        match self.editing {
+            Some(room_id) => Task::perform(update_room(client, room_id, input), |res| match res {
+                ApiResult::Success(_) => AppMessage::AddRoomMessage(AddRoomMessage::RoomUpdated),
+                ApiResult::Forbidden => AppMessage::TokenExpired,
+                ApiResult::BadRequest(err) => {
+                    AppMessage::AddRoomMessage(AddRoomMessage::ShowError(err))
+                }
+                ApiResult::Error(err) => {
+                    println!("Error updating room: {err}");
+                    show_notification("Unexpected error", NotificationType::Error)
+                }
+            }),
+            None => Task::perform(
+                services::add_room::add_room(client, input),
+                |res| match res {
+                    ApiResult::Success(uuid) => {
+                        AppMessage::AddRoomMessage(AddRoomMessage::RoomAdded(uuid))
+                    }
+                    ApiResult::Forbidden => AppMessage::TokenExpired,
+                    ApiResult::BadRequest(bad_request) => {
+                        AppMessage::AddRoomMessage(AddRoomMessage::ShowError(bad_request))
+                    }
+                    ApiResult::Error(err) => {
+                        AppMessage::AddRoomMessage(AddRoomMessage::ShowError(err))
+                    }
+                },
+            ),
         }
     }
 
@@ -155,6 +221,10 @@ impl AddRoomScreen {
     }
 }
 impl Screen for AddRoomScreen {
+    fn keep_alive(&self) -> bool {
+        true
+    }
+
     fn update(
         &mut self,
         message: AppMessage,
@@ -203,39 +273,15 @@ impl Screen for AddRoomScreen {
                     self.id_counter += 1;
                     Task::none()
                 }
-                AddRoomMessage::AddRoom => {
-                    let add_room_input = self.get_input();
-                    match add_room_input {
-                        Ok(input) => {
-                            let global_state_input = global_state.clone();
-
-                            Task::perform(
-                                services::add_room::add_room(global_state_input, input),
-                                move |res| match res {
-                                    Ok(AddRoomResult::Added(uuid)) => {
-                                        AppMessage::AddRoomMessage(AddRoomMessage::RoomAdded(uuid))
-                                    }
-                                    Ok(AddRoomResult::Forbidden) => AppMessage::TokenExpired,
-                                    Ok(AddRoomResult::BadRequest(bad_request)) => {
-                                        AppMessage::AddRoomMessage(AddRoomMessage::ShowError(
-                                            bad_request,
-                                        ))
-                                    }
-                                    Err(err) => {
-                                        AppMessage::AddRoomMessage(AddRoomMessage::ShowError(err))
-                                    }
-                                },
-                            )
-                        }
-                        Err(input_err) => Task::done(AppMessage::AddRoomMessage(
-                            AddRoomMessage::ShowError(input_err),
-                        )),
-                    }
-                }
+                AddRoomMessage::AddRoom => self.submit(global_state),
                 AddRoomMessage::RoomAdded(_uuid) => {
                     self.clear_inputs();
                     Task::done(show_notification("Room added", NotificationType::Success))
                 }
+                AddRoomMessage::RoomUpdated => {
+                    Task::done(show_notification("Room updated", NotificationType::Success))
+                        .chain(Task::done(AppMessage::NavigateTo(ScreenType::Rooms)))
+                }
                 AddRoomMessage::ShowError(err) => {
                     self.error = err;
                     Task::none()
@@ -254,9 +300,14 @@ impl Screen for AddRoomScreen {
     }
 
     fn view(&self, global_state: Arc<Mutex<GlobalState>>) -> Element<AppMessage> {
+        let (title, submit_text) = match self.editing {
+            Some(_) => ("Edit Room", "Save"),
+            None => ("Add Room", "Add"),
+        };
+
         scrollable(
             column![
-                text!("Add Room")
+                text!("{title}")
                     .align_x(Center)
                     .size(TITLE_FONT_SIZE)
                     .width(Fill),
@@ -291,7 +342,7 @@ impl Screen for AddRoomScreen {
                     .size(18)
                     .align_x(Center)
                     .width(Fill),
-                button("Add")
+                button(submit_text)
                     .on_press(AppMessage::AddRoomMessage(AddRoomMessage::AddRoom))
                     .height(30)
                     .width(80)
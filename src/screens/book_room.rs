@@ -11,51 +11,49 @@ use iced_aw::date_picker::Date;
 use uuid::Uuid;
 
 use crate::{
-    app::{AppMessage, GlobalState, Screen},
+    app::{AppMessage, GlobalState, Screen, ScreenType},
     components::{
         checkbox::Checkbox,
         date_input::DateInput,
         focus_chain::FocusChain,
-        list_input::{guest_list_input::GuestListInput, room_list_input::RoomListInput},
+        list_input::guest_list_input::GuestListInput,
         notification::NotificationType,
+        room_search::{RoomSearch, RoomSearchMessage, ROOM_SEARCH_IDS},
         text_box::{
-            number_text_box::{NumberTextBox, NumberType},
             phone_number_text_box::PhoneNumberTextBox,
             text_box::{TextBox, TextElement},
             ucn_text_box::UcnTextBox,
         },
     },
-    model::{guest::Guest, room::Room},
+    model::{guest::Guest, ucn::Ucn},
     services::{
-        find_guest::{find_guest, FindGuestInput, FindGuestResult},
-        find_unoccupied_rooms::{
-            find_unoccupied_rooms, FindUnoccupiedRoomsInput, FindUnoccupiedRoomsResult,
-        },
-        get_guest::GetGuestResult,
-        get_room::GetRoomResult,
+        api_client::{ApiClient, ApiResult},
+        create_booking::{create_booking, CreateBookingInput},
+        find_guest::{find_guest, FindGuestInput},
+    },
+    styles::{
+        ERROR_COLOR, FORM_PADDING, FORM_SPACING, TEXT_BOX_WIDTH, TITLE_FONT_SIZE, WARNING_COLOR,
     },
-    styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TEXT_BOX_WIDTH, TITLE_FONT_SIZE},
-    utils::show_notification,
+    utils::{add_days, is_date_before, is_same_date, show_notification},
 };
 
 #[derive(Debug, Clone, Copy)]
-enum BookRoomStep {
+pub enum BookRoomStep {
     DateAndRoom,
     AddGuests,
+    Confirm,
+    Booked,
 }
 impl BookRoomStep {
     fn get_focus_chain(self) -> FocusChain {
         match self {
-            BookRoomStep::DateAndRoom => FocusChain::new(DATE_AND_ROOM_IDS.into()),
+            BookRoomStep::DateAndRoom => FocusChain::new(ROOM_SEARCH_IDS.into()),
             BookRoomStep::AddGuests => FocusChain::new(ADD_GUESTS_IDS.into()),
+            BookRoomStep::Confirm | BookRoomStep::Booked => FocusChain::new(vec![]),
         }
     }
 }
 
-const MIN_CAPACITY_ID: &str = "Book Room Min Capacity";
-const MAX_CAPACITY_ID: &str = "Book Room Min Capacity";
-const DATE_AND_ROOM_IDS: [&str; 2] = [MIN_CAPACITY_ID, MAX_CAPACITY_ID];
-
 const FIEST_NAME_ID: &str = "Book Room First Name";
 const LAST_NAME_ID: &str = "Book Room Last Name";
 const PHONE_NUMBER_ID: &str = "Book Room Phone";
@@ -66,17 +64,7 @@ const ADD_GUESTS_IDS: [&str; 4] = [FIEST_NAME_ID, LAST_NAME_ID, UCN_ID, PHONE_NU
 pub enum BookRoomMessage {
     SetError(String),
     SetStep(BookRoomStep),
-    ChangeMinimumCapacity(String),
-    ChangeMaximumCapacity(String),
-    ChangeStartDate(Date),
-    ToggleShowStartDate,
-    ChangeEndDate(Date),
-    ToggleShowEndDate,
-    FindFreeRooms,
-    FoundFreeRooms(Vec<Uuid>),
-    RoomLoaded(Box<Room>),
-    ScrollRooms(f32),
-    SelectRoom(Uuid),
+    RoomSearch(RoomSearchMessage),
     ChangeFirstName(String),
     ChangeLastName(String),
     ChangeUCN(String),
@@ -91,16 +79,15 @@ pub enum BookRoomMessage {
     AddGuest(Uuid),
     SetSelectedMainGuest(Uuid),
     RemoveAddedGuest(Uuid),
+    ReviewBooking,
+    ConfirmBooking,
+    BookingCreated(Uuid),
 }
 
 pub struct BookRoomScreen {
     current_step: BookRoomStep,
     focus_chain: FocusChain,
-    minimum_capacity_input: NumberTextBox,
-    maximum_capacity_input: NumberTextBox,
-    start_date_input: DateInput,
-    end_date_input: DateInput,
-    select_room_input: RoomListInput,
+    room_search: RoomSearch,
     select_guest_input: GuestListInput,
     selected_guests: Vec<Guest>,
     main_guest_id: Option<Uuid>,
@@ -110,6 +97,8 @@ pub struct BookRoomScreen {
     phone_number_input: PhoneNumberTextBox,
     date_of_birth_input: DateInput,
     search_by_date_of_birth_checkbox: Checkbox,
+    booking_id: Option<Uuid>,
+    search_on_open: bool,
     error: String,
 }
 impl BookRoomScreen {
@@ -117,20 +106,10 @@ impl BookRoomScreen {
         Self {
             current_step: BookRoomStep::DateAndRoom,
             error: "".to_owned(),
-            minimum_capacity_input: NumberTextBox::new("", 2, NumberType::PositiveInteger),
-            maximum_capacity_input: NumberTextBox::new("", 2, NumberType::PositiveInteger),
-            start_date_input: DateInput::new(
-                "Start",
-                Date::today(),
-                AppMessage::BookRoomMessage(BookRoomMessage::ToggleShowStartDate),
-            ),
-            end_date_input: DateInput::new(
-                "End",
-                Date::today(),
-                AppMessage::BookRoomMessage(BookRoomMessage::ToggleShowEndDate),
-            ),
+            room_search: RoomSearch::new(|x| {
+                AppMessage::BookRoomMessage(BookRoomMessage::RoomSearch(x))
+            }),
             focus_chain: BookRoomStep::DateAndRoom.get_focus_chain(),
-            select_room_input: RoomListInput::new(),
             select_guest_input: GuestListInput::new(),
             selected_guests: vec![],
             first_name_input: TextBox::new("", 20),
@@ -144,56 +123,28 @@ impl BookRoomScreen {
             ),
             search_by_date_of_birth_checkbox: Checkbox::new("Search by date of birth", false),
             main_guest_id: None,
+            booking_id: None,
+            search_on_open: false,
         }
     }
 
+    pub fn prefilled(room_id: Uuid, start_date: Date) -> Self {
+        let mut screen = Self::new();
+        screen
+            .room_search
+            .prefill(room_id, start_date, add_days(start_date, 1));
+        screen.search_on_open = true;
+
+        screen
+    }
+
     fn view_date_and_room(&self) -> Element<AppMessage> {
         column![
             text!("Book Room")
                 .align_x(Center)
                 .size(TITLE_FONT_SIZE)
                 .width(Fill),
-            row![
-                text_input("Min capacity", self.minimum_capacity_input.get_text())
-                    .id(MIN_CAPACITY_ID)
-                    .on_input(|x| AppMessage::BookRoomMessage(
-                        BookRoomMessage::ChangeMinimumCapacity(x)
-                    ))
-                    .align_x(Center)
-                    .width(120)
-                    .line_height(1.5),
-                text_input("Max capacity", self.maximum_capacity_input.get_text())
-                    .id(MIN_CAPACITY_ID)
-                    .on_input(|x| AppMessage::BookRoomMessage(
-                        BookRoomMessage::ChangeMaximumCapacity(x)
-                    ))
-                    .align_x(Center)
-                    .width(120)
-                    .line_height(1.5),
-            ]
-            .spacing(10),
-            row![
-                self.start_date_input
-                    .view(|x| AppMessage::BookRoomMessage(BookRoomMessage::ChangeStartDate(x))),
-                self.end_date_input
-                    .view(|x| AppMessage::BookRoomMessage(BookRoomMessage::ChangeEndDate(x))),
-            ]
-            .spacing(10),
-            button("Find")
-                .on_press(AppMessage::BookRoomMessage(BookRoomMessage::FindFreeRooms))
-                .height(30)
-                .width(80),
-            text!("{}", self.error)
-                .color(ERROR_COLOR)
-                .size(18)
-                .align_x(Center)
-                .width(Fill),
-            self.select_room_input.view(
-                |id| AppMessage::BookRoomMessage(BookRoomMessage::SelectRoom(id)),
-                |x| AppMessage::BookRoomMessage(BookRoomMessage::ScrollRooms(
-                    x.relative_offset().y
-                ))
-            ),
+            self.room_search.view(),
             button("Next")
                 .on_press(AppMessage::BookRoomMessage(BookRoomMessage::SetStep(
                     BookRoomStep::AddGuests
@@ -267,12 +218,19 @@ impl BookRoomScreen {
                 ))
             ),
             self.view_added_guests_list(),
-            button("Previous")
-                .on_press(AppMessage::BookRoomMessage(BookRoomMessage::SetStep(
-                    BookRoomStep::DateAndRoom
-                )))
-                .height(30)
-                .width(80)
+            row![
+                button("Previous")
+                    .on_press(AppMessage::BookRoomMessage(BookRoomMessage::SetStep(
+                        BookRoomStep::DateAndRoom
+                    )))
+                    .height(30)
+                    .width(80),
+                button("Next")
+                    .on_press(AppMessage::BookRoomMessage(BookRoomMessage::ReviewBooking))
+                    .height(30)
+                    .width(80)
+            ]
+            .spacing(10)
         ]
         .spacing(FORM_SPACING)
         .align_x(Center)
@@ -280,66 +238,107 @@ impl BookRoomScreen {
         .into()
     }
 
-    fn get_optional_number(number_str: &str) -> Option<i16> {
-        if number_str.is_empty() {
-            return None;
-        }
-
-        number_str.parse::<i16>().map_or(None, |x| Some(x))
-    }
+    fn view_confirm(&self) -> Element<'_, AppMessage> {
+        let room_text = self
+            .room_search
+            .selected_room()
+            .map_or("".to_owned(), |room| {
+                format!(
+                    "Room {}, floor {}, price {:.2}",
+                    room.room_number,
+                    room.floor,
+                    room.price as f64 / 100.0
+                )
+            });
 
-    fn find_free_rooms(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
-        let input = FindUnoccupiedRoomsInput {
-            start_date: self.start_date_input.get_date(),
-            end_date: self.end_date_input.get_date(),
-            minimum_capacity: Self::get_optional_number(self.minimum_capacity_input.get_text()),
-            maximum_capacity: Self::get_optional_number(self.maximum_capacity_input.get_text()),
-        };
+        let mut guests_list = column![].spacing(5);
+        for guest in &self.selected_guests {
+            let main_guest_text = if self.main_guest_id == Some(guest.id) {
+                " (main guest)"
+            } else {
+                ""
+            };
+            guests_list = guests_list.push(text!(
+                "{} {}, {}{}",
+                guest.first_name,
+                guest.last_name,
+                guest.date_of_birth,
+                main_guest_text
+            ));
+            if let Some(warning) = guest.get_document_warning(self.room_search.end_date()) {
+                guests_list = guests_list.push(text(warning).color(WARNING_COLOR).size(14));
+            }
+        }
 
-        Task::perform(
-            find_unoccupied_rooms(global_state, input),
-            |res| match res {
-                Ok(FindUnoccupiedRoomsResult::Found(ids)) => {
-                    AppMessage::BookRoomMessage(BookRoomMessage::FoundFreeRooms(ids))
-                }
-                Ok(FindUnoccupiedRoomsResult::Forbidden) => AppMessage::TokenExpired,
-                Ok(FindUnoccupiedRoomsResult::BadRequest(err)) => {
-                    AppMessage::BookRoomMessage(BookRoomMessage::SetError(err))
-                }
-                Err(err) => {
-                    println!("Error finding free rooms: '{err}'");
-                    show_notification("Unexpected Error", NotificationType::Error)
-                }
-            },
-        )
+        column![
+            text!("Confirm booking")
+                .align_x(Center)
+                .size(TITLE_FONT_SIZE)
+                .width(Fill),
+            text!("{room_text}"),
+            text!(
+                "From {} to {}",
+                self.room_search.start_date(),
+                self.room_search.end_date()
+            ),
+            text!("Guests:"),
+            container(guests_list).width(480),
+            text!("{}", self.error)
+                .color(ERROR_COLOR)
+                .size(18)
+                .align_x(Center)
+                .width(Fill),
+            row![
+                button("Previous")
+                    .on_press(AppMessage::BookRoomMessage(BookRoomMessage::SetStep(
+                        BookRoomStep::AddGuests
+                    )))
+                    .height(30)
+                    .width(80),
+                button("Confirm booking")
+                    .on_press(AppMessage::BookRoomMessage(BookRoomMessage::ConfirmBooking))
+                    .height(30)
+                    .width(150)
+            ]
+            .spacing(10)
+        ]
+        .spacing(FORM_SPACING)
+        .align_x(Center)
+        .padding(FORM_PADDING)
+        .into()
     }
 
-    fn map_get_room_result(result: Result<GetRoomResult, String>) -> AppMessage {
-        match result {
-            Ok(GetRoomResult::Found(room)) => {
-                AppMessage::BookRoomMessage(BookRoomMessage::RoomLoaded(Box::new(room)))
-            }
-            Ok(GetRoomResult::Forbidden) => AppMessage::TokenExpired,
-            Ok(GetRoomResult::BadRequest(err)) => {
-                AppMessage::BookRoomMessage(BookRoomMessage::SetError(err))
-            }
-            Err(err) => {
-                println!("Error fetching rooms: {err}");
-                show_notification("Unexpected_message", NotificationType::Error)
-            }
-        }
+    fn view_booked(&self) -> Element<'_, AppMessage> {
+        column![
+            text!("Booking confirmed")
+                .align_x(Center)
+                .size(TITLE_FONT_SIZE)
+                .width(Fill),
+            text!(
+                "Booking id: {}",
+                self.booking_id.map_or("".to_owned(), |id| id.to_string())
+            ),
+            button("New booking")
+                .on_press(AppMessage::NavigateTo(ScreenType::BookRoom))
+                .height(30)
+                .width(150)
+        ]
+        .spacing(FORM_SPACING)
+        .align_x(Center)
+        .padding(FORM_PADDING)
+        .into()
     }
 
-    fn map_get_guest_result(result: Result<GetGuestResult, String>) -> AppMessage {
+    fn map_get_guest_result(result: ApiResult<Guest>) -> AppMessage {
         match result {
-            Ok(GetGuestResult::Found(guest)) => {
-                AppMessage::BookRoomMessage(BookRoomMessage::GuestLoaded(guest))
+            ApiResult::Success(guest) => {
+                AppMessage::BookRoomMessage(BookRoomMessage::GuestLoaded(Box::new(guest)))
             }
-            Ok(GetGuestResult::Forbidden) => AppMessage::TokenExpired,
-            Ok(GetGuestResult::BadRequest(err)) => {
+            ApiResult::Forbidden => AppMessage::TokenExpired,
+            ApiResult::BadRequest(err) => {
                 AppMessage::BookRoomMessage(BookRoomMessage::SetError(err))
             }
-            Err(err) => {
+            ApiResult::Error(err) => {
                 println!("Error fetching guest: {err}");
                 show_notification("Unexpected_message", NotificationType::Error)
             }
@@ -354,10 +353,10 @@ impl BookRoomScreen {
         }
     }
 
-    fn get_find_guests_input(&self) -> FindGuestInput {
+    fn get_find_guests_input(&self) -> Result<FindGuestInput, String> {
         let first_name = Self::get_text_if_not_empty(&self.first_name_input);
         let last_name = Self::get_text_if_not_empty(&self.last_name_input);
-        let phone_number = Self::get_text_if_not_empty(&self.phone_number_input);
+        let phone_number = self.phone_number_input.get_normalized()?;
         let ucn = Self::get_text_if_not_empty(&self.ucn_input);
         let date_of_birth = if self.search_by_date_of_birth_checkbox.is_checked() {
             Some(self.date_of_birth_input.get_date().to_string())
@@ -365,33 +364,66 @@ impl BookRoomScreen {
             None
         };
 
-        FindGuestInput {
+        Ok(FindGuestInput {
             first_name,
             last_name,
             date_of_birth,
             ucn,
             phone_number,
+            ..Default::default()
+        })
+    }
+
+    fn apply_ucn(&mut self) {
+        let Ok(ucn) = Ucn::parse(self.ucn_input.get_text()) else {
+            return;
+        };
+        let date_of_birth = self.date_of_birth_input.get_date();
+
+        if !self.search_by_date_of_birth_checkbox.is_checked() {
+            self.date_of_birth_input.update_date(ucn.date_of_birth);
+        } else if !is_same_date(date_of_birth, ucn.date_of_birth) {
+            self.error = format!(
+                "UCN date of birth {} doesn't match {date_of_birth}",
+                ucn.date_of_birth
+            );
         }
     }
 
     fn find_guests(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
         self.error = "".to_owned();
-        let input = self.get_find_guests_input();
-
-        Task::perform(find_guest(global_state, input), |res| match res {
-            Ok(FindGuestResult::Found(ids)) => {
-                println!("Found guests count: {}", ids.len());
-                AppMessage::BookRoomMessage(BookRoomMessage::FoundGuests(ids))
-            }
-            Ok(FindGuestResult::Forbidden) => AppMessage::TokenExpired,
-            Ok(FindGuestResult::BadRequest(err)) => {
-                AppMessage::BookRoomMessage(BookRoomMessage::SetError(err))
+        let ucn = self.ucn_input.get_text();
+        if !ucn.is_empty() {
+            if let Err(err) = Ucn::parse(ucn) {
+                self.error = err;
+                return Task::none();
             }
+        }
+        let input = match self.get_find_guests_input() {
+            Ok(ok) => ok,
             Err(err) => {
-                println!("Error finding guests: {err}");
-                show_notification("Unexpected error", NotificationType::Error)
+                self.error = err;
+                return Task::none();
             }
-        })
+        };
+
+        Task::perform(
+            find_guest(ApiClient::new(&global_state), input),
+            |res| match res {
+                ApiResult::Success(ids) => {
+                    println!("Found guests count: {}", ids.len());
+                    AppMessage::BookRoomMessage(BookRoomMessage::FoundGuests(ids))
+                }
+                ApiResult::Forbidden => AppMessage::TokenExpired,
+                ApiResult::BadRequest(err) => {
+                    AppMessage::BookRoomMessage(BookRoomMessage::SetError(err))
+                }
+                ApiResult::Error(err) => {
+                    println!("Error finding guests: {err}");
+                    show_notification("Unexpected error", NotificationType::Error)
+                }
+            },
+        )
     }
 
     fn add_guest_to_booking(&mut self, guest_id: Uuid) -> Task<AppMessage> {
@@ -400,13 +432,108 @@ impl BookRoomScreen {
             .iter()
             .find(|guest| guest.id == guest_id);
 
-        if is_added.is_none() {
-            self.select_guest_input
-                .get_loaded(guest_id)
-                .map(|guest| self.selected_guests.push(guest));
+        if is_added.is_some() {
+            return Task::none();
+        }
+        let Some(guest) = self.select_guest_input.get_loaded(guest_id) else {
+            return Task::none();
+        };
+        let warning = guest.get_document_warning(self.room_search.end_date());
+        self.selected_guests.push(guest);
+
+        match warning {
+            Some(warning) => Task::done(show_notification(
+                format!("{warning}, ask for an updated document"),
+                NotificationType::Warning,
+            )),
+            None => Task::none(),
+        }
+    }
+
+    fn get_booking_input(&self) -> Result<CreateBookingInput, String> {
+        let room_id = if let Some(some) = self.room_search.selected_room_id() {
+            some
+        } else {
+            return Err("Select a room".to_owned());
+        };
+        let start_date = self.room_search.start_date();
+        let end_date = self.room_search.end_date();
+        if is_date_before(start_date, Date::today()) {
+            return Err("Start date can't be in the past".to_owned());
+        }
+        if !is_date_before(start_date, end_date) {
+            return Err("End date must be after start date".to_owned());
         }
+        let dates_searched = self
+            .room_search
+            .searched_dates()
+            .is_some_and(|(start, end)| {
+                is_same_date(start, start_date) && is_same_date(end, end_date)
+            });
+        if !dates_searched {
+            return Err("Dates changed, search for free rooms again".to_owned());
+        }
+        let main_guest = if let Some(some) = self.main_guest_id {
+            some
+        } else {
+            return Err("Select a main guest".to_owned());
+        };
+        let other_guests = self
+            .selected_guests
+            .iter()
+            .map(|guest| guest.id)
+            .filter(|id| *id != main_guest)
+            .collect();
 
-        Task::none()
+        Ok(CreateBookingInput::new(
+            room_id,
+            main_guest,
+            other_guests,
+            start_date,
+            end_date,
+        ))
+    }
+
+    fn review_booking(&mut self) -> Task<AppMessage> {
+        match self.get_booking_input() {
+            Ok(_) => {
+                self.error = "".to_owned();
+                Task::done(AppMessage::BookRoomMessage(BookRoomMessage::SetStep(
+                    BookRoomStep::Confirm,
+                )))
+            }
+            Err(err) => {
+                self.error = err;
+                Task::none()
+            }
+        }
+    }
+
+    fn confirm_booking(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        let input = match self.get_booking_input() {
+            Ok(ok) => ok,
+            Err(err) => {
+                self.error = err;
+                return Task::none();
+            }
+        };
+
+        Task::perform(
+            create_booking(ApiClient::new(&global_state), input),
+            |res| match res {
+                ApiResult::Success(booking_id) => {
+                    AppMessage::BookRoomMessage(BookRoomMessage::BookingCreated(booking_id))
+                }
+                ApiResult::Forbidden => AppMessage::TokenExpired,
+                ApiResult::BadRequest(err) => {
+                    AppMessage::BookRoomMessage(BookRoomMessage::SetError(err))
+                }
+                ApiResult::Error(err) => {
+                    println!("Error creating booking: {err}");
+                    show_notification("Unexpected error", NotificationType::Error)
+                }
+            },
+        )
     }
 
     fn view_added_guest(&self, guest: &Guest) -> Element<AppMessage> {
@@ -420,7 +547,11 @@ impl BookRoomScreen {
                 .width(80)
         };
 
-        row![
+        let warning = guest
+            .get_document_warning(self.room_search.end_date())
+            .map(|warning| text(warning).color(WARNING_COLOR).size(14));
+
+        column![row![
             text!("{} {}", guest.first_name, guest.last_name).width(220),
             button("Remove")
                 .on_press(AppMessage::BookRoomMessage(
@@ -429,7 +560,8 @@ impl BookRoomScreen {
                 .width(80),
             select_main_guest_button
         ]
-        .spacing(10)
+        .spacing(10)]
+        .push_maybe(warning)
         .into()
     }
 
@@ -447,6 +579,20 @@ impl BookRoomScreen {
     }
 }
 impl Screen for BookRoomScreen {
+    fn keep_alive(&self) -> bool {
+        true
+    }
+
+    fn on_open(&mut self, _global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        if !std::mem::take(&mut self.search_on_open) {
+            return Task::none();
+        }
+
+        Task::done(AppMessage::BookRoomMessage(BookRoomMessage::RoomSearch(
+            RoomSearchMessage::FindFreeRooms,
+        )))
+    }
+
     fn update(
         &mut self,
         message: AppMessage,
@@ -463,53 +609,11 @@ impl Screen for BookRoomScreen {
                     self.focus_chain = book_room_step.get_focus_chain();
                     Task::none()
                 }
-                BookRoomMessage::ChangeMinimumCapacity(min_capacity) => {
-                    self.focus_chain.set_focus(Some(MIN_CAPACITY_ID));
-                    self.minimum_capacity_input.update(min_capacity);
-                    Task::none()
-                }
-                BookRoomMessage::ChangeMaximumCapacity(max_capacity) => {
-                    self.focus_chain.set_focus(Some(MAX_CAPACITY_ID));
-                    self.maximum_capacity_input.update(max_capacity);
-                    Task::none()
-                }
-                BookRoomMessage::ChangeStartDate(date) => {
-                    self.start_date_input.update_date(date);
-                    self.start_date_input.toggle_show();
-                    Task::none()
-                }
-                BookRoomMessage::ToggleShowStartDate => {
-                    self.start_date_input.toggle_show();
-                    Task::none()
-                }
-                BookRoomMessage::ChangeEndDate(date) => {
-                    self.end_date_input.update_date(date);
-                    self.end_date_input.toggle_show();
-                    Task::none()
-                }
-                BookRoomMessage::ToggleShowEndDate => {
-                    self.end_date_input.toggle_show();
-                    Task::none()
-                }
-                BookRoomMessage::FindFreeRooms => self.find_free_rooms(global_state),
-                BookRoomMessage::FoundFreeRooms(ids) => {
-                    self.error = "".to_owned();
-                    self.select_room_input
-                        .update_ids(global_state, ids, Self::map_get_room_result)
-                }
-                BookRoomMessage::RoomLoaded(room) => {
-                    self.select_room_input.update_loaded(*room);
-                    Task::none()
-                }
-                BookRoomMessage::ScrollRooms(amount) => self.select_room_input.load_scrolled(
+                BookRoomMessage::RoomSearch(room_search_message) => self.room_search.update(
+                    room_search_message,
                     global_state,
-                    amount,
-                    Self::map_get_room_result,
+                    &mut self.focus_chain,
                 ),
-                BookRoomMessage::SelectRoom(uuid) => {
-                    self.select_room_input.set_selected(Some(uuid));
-                    Task::none()
-                }
                 BookRoomMessage::ChangeFirstName(first_name) => {
                     self.focus_chain.set_focus(Some(FIEST_NAME_ID));
                     self.first_name_input.update(first_name);
@@ -523,6 +627,7 @@ impl Screen for BookRoomScreen {
                 BookRoomMessage::ChangeUCN(ucn) => {
                     self.focus_chain.set_focus(Some(UCN_ID));
                     self.ucn_input.update(ucn);
+                    self.apply_ucn();
                     Task::none()
                 }
                 BookRoomMessage::ChangeDateOfBirth(date) => {
@@ -569,6 +674,18 @@ impl Screen for BookRoomScreen {
 
                     Task::none()
                 }
+                BookRoomMessage::ReviewBooking => self.review_booking(),
+                BookRoomMessage::ConfirmBooking => self.confirm_booking(global_state),
+                BookRoomMessage::BookingCreated(booking_id) => {
+                    self.error = "".to_owned();
+                    self.booking_id = Some(booking_id);
+                    self.current_step = BookRoomStep::Booked;
+                    self.focus_chain = BookRoomStep::Booked.get_focus_chain();
+                    Task::done(show_notification(
+                        "Booking created",
+                        NotificationType::Success,
+                    ))
+                }
             },
             AppMessage::SelectNext => {
                 self.focus_chain.set_next();
@@ -586,6 +703,8 @@ impl Screen for BookRoomScreen {
         let current_view = match self.current_step {
             BookRoomStep::DateAndRoom => self.view_date_and_room(),
             BookRoomStep::AddGuests => self.view_add_guests(),
+            BookRoomStep::Confirm => self.view_confirm(),
+            BookRoomStep::Booked => self.view_booked(),
         };
 
         scrollable(current_view).into()
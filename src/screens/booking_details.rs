@@ -0,0 +1,790 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use iced::{
+    widget::{button, column, row, scrollable, text, text_input, Column},
+    Alignment::Center,
+    Element,
+    Length::Fill,
+    Task,
+};
+use iced_aw::date_picker::Date;
+use uuid::Uuid;
+
+use crate::{
+    app::{AppMessage, GlobalState, Screen},
+    components::{
+        date_input::DateInput,
+        notification::NotificationType,
+        text_box::text_box::{TextBox, TextElement},
+    },
+    model::{booking::Booking, booking_status::BookingStatus, guest::Guest, room::Room},
+    services::{
+        api_client::{ApiClient, ApiResult},
+        cancel_booking::{cancel_booking, CancelBookingInput},
+        change_booking_dates::{change_booking_dates, ChangeBookingDatesInput},
+        find_guest::{find_guests_matching_any, search_queries},
+        find_unoccupied_rooms::{
+            find_unoccupied_rooms, is_room_unoccupied, FindUnoccupiedRoomsInput, RoomFilters,
+        },
+        get_booking::get_booking,
+        get_guest::get_guest,
+        get_room::get_room,
+        move_booking::{move_booking, MoveBookingInput},
+        update_booking_guests::{update_booking_guests, UpdateBookingGuestsInput},
+    },
+    styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE, WARNING_COLOR},
+    utils::{is_date_before, is_same_date, show_notification},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookingAction {
+    Cancel,
+    ChangeDates,
+    MoveRoom,
+    EditGuests,
+}
+
+#[derive(Debug, Clone)]
+pub enum BookingDetailsMessage {
+    RoomLoaded(Box<Room>),
+    GuestLoaded(Box<Guest>),
+    BookingLoaded(Box<Booking>),
+    OpenAction(BookingAction),
+    CloseAction,
+    ChangeCancelReason(String),
+    ConfirmCancel,
+    ToggleShowStartDate,
+    ChangeStartDate(Date),
+    ToggleShowEndDate,
+    ChangeEndDate(Date),
+    CheckAvailability,
+    AvailabilityChecked(bool),
+    ConfirmDates,
+    FreeRoomsFound(Vec<Uuid>),
+    MoveToRoom(Uuid),
+    ChangeGuestSearch(String),
+    FindGuests,
+    GuestsFound(Vec<Uuid>),
+    AddGuest(Uuid),
+    RemoveGuest(Uuid),
+    MakeMainGuest(Uuid),
+    SaveGuests,
+    BookingChanged(String),
+    SetError(String),
+}
+
+pub struct BookingDetailsScreen {
+    booking: Booking,
+    rooms: HashMap<Uuid, Room>,
+    guests: HashMap<Uuid, Guest>,
+    action: Option<BookingAction>,
+    cancel_reason_input: TextBox,
+    start_date_input: DateInput,
+    end_date_input: DateInput,
+    available_dates: Option<(Date, Date)>,
+    free_room_ids: Option<Vec<Uuid>>,
+    edited_guests: Vec<Uuid>,
+    guest_search_input: TextBox,
+    found_guests: Vec<Uuid>,
+    error: String,
+}
+impl BookingDetailsScreen {
+    pub fn new(booking: &Booking) -> Self {
+        Self {
+            booking: booking.clone(),
+            rooms: HashMap::new(),
+            guests: HashMap::new(),
+            action: None,
+            cancel_reason_input: TextBox::new("", 200),
+            start_date_input: DateInput::new(
+                "Check-in",
+                booking.start_date,
+                AppMessage::BookingDetailsMessage(BookingDetailsMessage::ToggleShowStartDate),
+            ),
+            end_date_input: DateInput::new(
+                "Check-out",
+                booking.end_date,
+                AppMessage::BookingDetailsMessage(BookingDetailsMessage::ToggleShowEndDate),
+            ),
+            available_dates: None,
+            free_room_ids: None,
+            edited_guests: vec![],
+            guest_search_input: TextBox::new("", 40),
+            found_guests: vec![],
+            error: "".to_owned(),
+        }
+    }
+
+    fn map_result<T, F>(result: ApiResult<T>, on_success: F) -> AppMessage
+    where
+        F: FnOnce(T) -> BookingDetailsMessage,
+    {
+        match result {
+            ApiResult::Success(ok) => AppMessage::BookingDetailsMessage(on_success(ok)),
+            ApiResult::Forbidden => AppMessage::TokenExpired,
+            ApiResult::BadRequest(err) => {
+                AppMessage::BookingDetailsMessage(BookingDetailsMessage::SetError(err))
+            }
+            ApiResult::Error(err) => {
+                println!("Error loading booking details: {err}");
+                show_notification("Unexpected error", NotificationType::Error)
+            }
+        }
+    }
+
+    fn can_modify(&self) -> bool {
+        matches!(
+            self.booking.status,
+            BookingStatus::Booked | BookingStatus::CheckedIn
+        )
+    }
+
+    fn load_room(&self, global_state: &Arc<Mutex<GlobalState>>, id: Uuid) -> Task<AppMessage> {
+        if self.rooms.contains_key(&id) {
+            return Task::none();
+        }
+
+        Task::perform(get_room(ApiClient::new(global_state), id), |res| {
+            Self::map_result(res, |room| {
+                BookingDetailsMessage::RoomLoaded(Box::new(room))
+            })
+        })
+    }
+
+    fn load_guests(
+        &self,
+        global_state: &Arc<Mutex<GlobalState>>,
+        ids: Vec<Uuid>,
+    ) -> Task<AppMessage> {
+        let tasks: Vec<_> = ids
+            .into_iter()
+            .filter(|id| !self.guests.contains_key(id))
+            .map(|id| {
+                Task::perform(get_guest(ApiClient::new(global_state), id), |res| {
+                    Self::map_result(res, |guest| {
+                        BookingDetailsMessage::GuestLoaded(Box::new(guest))
+                    })
+                })
+            })
+            .collect();
+
+        Task::batch(tasks)
+    }
+
+    fn load_details(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        Task::batch([
+            self.load_room(&global_state, self.booking.room_id),
+            self.load_guests(&global_state, self.booking.guest_ids()),
+        ])
+    }
+
+    fn open_action(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        action: BookingAction,
+    ) -> Task<AppMessage> {
+        self.error = "".to_owned();
+        self.action = Some(action);
+        match action {
+            BookingAction::Cancel => {
+                self.cancel_reason_input.update("".to_owned());
+                Task::none()
+            }
+            BookingAction::ChangeDates => {
+                self.start_date_input.update_date(self.booking.start_date);
+                self.end_date_input.update_date(self.booking.end_date);
+                self.available_dates = None;
+                Task::none()
+            }
+            BookingAction::MoveRoom => {
+                self.free_room_ids = None;
+                let input = FindUnoccupiedRoomsInput {
+                    start_date: self.booking.start_date,
+                    end_date: self.booking.end_date,
+                    minimum_capacity: Some(self.booking.guest_ids().len() as i16),
+                    maximum_capacity: None,
+                    filters: RoomFilters::default(),
+                };
+
+                Task::perform(
+                    find_unoccupied_rooms(ApiClient::new(&global_state), input),
+                    |res| Self::map_result(res, BookingDetailsMessage::FreeRoomsFound),
+                )
+            }
+            BookingAction::EditGuests => {
+                self.edited_guests = self.booking.guest_ids();
+                self.guest_search_input.update("".to_owned());
+                self.found_guests.clear();
+                Task::none()
+            }
+        }
+    }
+
+    // The booking already holds its room for the current dates, so only the
+    // nights that are not part of the current stay need to be checked
+    fn get_added_nights(&self, start_date: Date, end_date: Date) -> Vec<(Date, Date)> {
+        let booking = &self.booking;
+        let overlaps = is_date_before(start_date, booking.end_date)
+            && is_date_before(booking.start_date, end_date);
+        if !overlaps {
+            return vec![(start_date, end_date)];
+        }
+
+        let mut ranges = vec![];
+        if is_date_before(start_date, booking.start_date) {
+            ranges.push((start_date, booking.start_date));
+        }
+        if is_date_before(booking.end_date, end_date) {
+            ranges.push((booking.end_date, end_date));
+        }
+
+        ranges
+    }
+
+    fn check_availability(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.error = "".to_owned();
+        self.available_dates = None;
+        let start_date = self.start_date_input.get_date();
+        let end_date = self.end_date_input.get_date();
+        if !is_date_before(start_date, end_date) {
+            self.error = "Check-out must be after check-in".to_owned();
+            return Task::none();
+        }
+
+        Task::perform(
+            is_room_unoccupied(
+                ApiClient::new(&global_state),
+                self.booking.room_id,
+                self.get_added_nights(start_date, end_date),
+            ),
+            |res| Self::map_result(res, BookingDetailsMessage::AvailabilityChecked),
+        )
+    }
+
+    fn are_dates_available(&self) -> bool {
+        self.available_dates.is_some_and(|(start_date, end_date)| {
+            is_same_date(start_date, self.start_date_input.get_date())
+                && is_same_date(end_date, self.end_date_input.get_date())
+        })
+    }
+
+    fn reload_booking(&self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        Task::perform(
+            get_booking(ApiClient::new(&global_state), self.booking.id),
+            |res| {
+                Self::map_result(res, |booking| {
+                    BookingDetailsMessage::BookingLoaded(Box::new(booking))
+                })
+            },
+        )
+    }
+
+    fn perform_change<F>(&self, future: F, success_text: &str) -> Task<AppMessage>
+    where
+        F: std::future::Future<Output = ApiResult<()>> + Send + 'static,
+    {
+        let success_text = success_text.to_owned();
+        Task::perform(future, move |res| {
+            Self::map_result(res, |_| {
+                BookingDetailsMessage::BookingChanged(success_text.clone())
+            })
+        })
+    }
+
+    fn validate_guests(&self) -> Result<(), String> {
+        let Some((main_guest, other_guests)) = self.edited_guests.split_first() else {
+            return Err("Booking needs at least one guest".to_owned());
+        };
+        if other_guests.contains(main_guest) {
+            return Err("Main guest is listed twice".to_owned());
+        }
+        if let Some(room) = self.rooms.get(&self.booking.room_id) {
+            if self.edited_guests.len() > room.capacity() as usize {
+                return Err(format!(
+                    "Room {} fits at most {} guests",
+                    room.room_number,
+                    room.capacity()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn view_field<'a>(label: &'a str, value: String) -> Element<'a, AppMessage> {
+        row![text(label).width(130), text(value)].spacing(10).into()
+    }
+
+    fn view_price(&self) -> Element<'_, AppMessage> {
+        let Some(room) = self.rooms.get(&self.booking.room_id) else {
+            return Self::view_field("Price", "Loading ...".to_owned());
+        };
+        let price_per_night = room.price as f64 / 100.0;
+        let total = self.booking.total_price(room) as f64 / 100.0;
+
+        column![
+            Self::view_field("Price per night", format!("{price_per_night:.2}")),
+            Self::view_field("Nights", format!("{}", self.booking.nights())),
+            Self::view_field("Total", format!("{total:.2}")),
+        ]
+        .spacing(FORM_SPACING)
+        .into()
+    }
+
+    fn view_guest(&self, guest_id: Uuid) -> Element<'_, AppMessage> {
+        let Some(guest) = self.guests.get(&guest_id) else {
+            return text("Loading guest ...").into();
+        };
+        let main_guest_text = if guest_id == self.booking.main_guest {
+            " (main guest)"
+        } else {
+            ""
+        };
+        let warning = guest
+            .get_document_warning(self.booking.end_date)
+            .map(|warning| text(warning).color(WARNING_COLOR).size(14));
+
+        column![text!(
+            "{} {}, {}{main_guest_text}",
+            guest.first_name,
+            guest.last_name,
+            guest.date_of_birth
+        )]
+        .push_maybe(warning)
+        .into()
+    }
+
+    fn view_guest_name(&self, guest_id: Uuid) -> String {
+        self.guests.get(&guest_id).map_or_else(
+            || "Loading guest ...".to_owned(),
+            |guest| format!("{} {}", guest.first_name, guest.last_name),
+        )
+    }
+
+    fn view_actions(&self) -> Element<'_, AppMessage> {
+        if !self.can_modify() {
+            return text!("{} bookings can no longer be changed", self.booking.status).into();
+        }
+        let action_button = |label, action| {
+            button(label).on_press(AppMessage::BookingDetailsMessage(
+                BookingDetailsMessage::OpenAction(action),
+            ))
+        };
+
+        row![
+            action_button("Change dates", BookingAction::ChangeDates),
+            action_button("Move to another room", BookingAction::MoveRoom),
+            action_button("Edit guests", BookingAction::EditGuests),
+        ]
+        .push_maybe(
+            (self.booking.status == BookingStatus::Booked)
+                .then(|| action_button("Cancel booking", BookingAction::Cancel)),
+        )
+        .spacing(10)
+        .into()
+    }
+
+    fn view_cancel(&self) -> Element<'_, AppMessage> {
+        column![
+            text("Cancel this booking? This cannot be undone."),
+            text_input("Reason", self.cancel_reason_input.get_text())
+                .on_input(|x| {
+                    AppMessage::BookingDetailsMessage(BookingDetailsMessage::ChangeCancelReason(x))
+                })
+                .on_submit(AppMessage::BookingDetailsMessage(
+                    BookingDetailsMessage::ConfirmCancel
+                ))
+                .width(400)
+                .line_height(1.5),
+            row![
+                button("Confirm cancellation").on_press(AppMessage::BookingDetailsMessage(
+                    BookingDetailsMessage::ConfirmCancel
+                )),
+                button("Back").on_press(AppMessage::BookingDetailsMessage(
+                    BookingDetailsMessage::CloseAction
+                )),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10)
+        .into()
+    }
+
+    fn view_change_dates(&self) -> Element<'_, AppMessage> {
+        let available = self.are_dates_available();
+        let status = if available {
+            text("Room is available for these dates")
+        } else {
+            text("Check availability before saving")
+        };
+
+        column![
+            row![
+                self.start_date_input.view(|x| {
+                    AppMessage::BookingDetailsMessage(BookingDetailsMessage::ChangeStartDate(x))
+                }),
+                self.end_date_input.view(|x| {
+                    AppMessage::BookingDetailsMessage(BookingDetailsMessage::ChangeEndDate(x))
+                }),
+            ]
+            .spacing(10),
+            status.size(14),
+            row![
+                button("Check availability").on_press(AppMessage::BookingDetailsMessage(
+                    BookingDetailsMessage::CheckAvailability
+                )),
+                button("Save dates").on_press_maybe(available.then_some(
+                    AppMessage::BookingDetailsMessage(BookingDetailsMessage::ConfirmDates)
+                )),
+                button("Back").on_press(AppMessage::BookingDetailsMessage(
+                    BookingDetailsMessage::CloseAction
+                )),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10)
+        .into()
+    }
+
+    fn view_move_room(&self) -> Element<'_, AppMessage> {
+        let back = button("Back").on_press(AppMessage::BookingDetailsMessage(
+            BookingDetailsMessage::CloseAction,
+        ));
+        let Some(ids) = &self.free_room_ids else {
+            return column![text("Finding free rooms ..."), back]
+                .spacing(10)
+                .into();
+        };
+        if ids.is_empty() {
+            return column![text("No other rooms are free for these dates"), back]
+                .spacing(10)
+                .into();
+        }
+
+        let rooms = ids.iter().map(|id| match self.rooms.get(id) {
+            Some(room) => row![
+                text!(
+                    "Room {}, floor {}, price {:.2}",
+                    room.room_number,
+                    room.floor,
+                    room.price as f64 / 100.0
+                )
+                .width(300),
+                button("Move here").on_press(AppMessage::BookingDetailsMessage(
+                    BookingDetailsMessage::MoveToRoom(room.id)
+                )),
+            ]
+            .spacing(10)
+            .align_y(Center)
+            .into(),
+            None => text("Loading room ...").into(),
+        });
+
+        column![Column::with_children(rooms).spacing(5), back]
+            .spacing(10)
+            .into()
+    }
+
+    fn view_edit_guests(&self) -> Element<'_, AppMessage> {
+        let booked_guests = self.edited_guests.iter().enumerate().map(|(index, id)| {
+            let guest_row = row![text(self.view_guest_name(*id)).width(250)]
+                .spacing(10)
+                .align_y(Center);
+            if index == 0 {
+                return guest_row.push(text("Main guest")).into();
+            }
+
+            guest_row
+                .push(
+                    button("Make main").on_press(AppMessage::BookingDetailsMessage(
+                        BookingDetailsMessage::MakeMainGuest(*id),
+                    )),
+                )
+                .push(button("Remove").on_press(AppMessage::BookingDetailsMessage(
+                    BookingDetailsMessage::RemoveGuest(*id),
+                )))
+                .into()
+        });
+        let found_guests = self
+            .found_guests
+            .iter()
+            .filter(|id| !self.edited_guests.contains(id))
+            .map(|id| {
+                row![
+                    text(self.view_guest_name(*id)).width(250),
+                    button("Add").on_press(AppMessage::BookingDetailsMessage(
+                        BookingDetailsMessage::AddGuest(*id)
+                    )),
+                ]
+                .spacing(10)
+                .align_y(Center)
+                .into()
+            });
+
+        column![
+            Column::with_children(booked_guests).spacing(5),
+            row![
+                text_input("Guest name or UCN", self.guest_search_input.get_text())
+                    .on_input(|x| {
+                        AppMessage::BookingDetailsMessage(BookingDetailsMessage::ChangeGuestSearch(
+                            x,
+                        ))
+                    })
+                    .on_submit(AppMessage::BookingDetailsMessage(
+                        BookingDetailsMessage::FindGuests
+                    ))
+                    .width(250)
+                    .line_height(1.5),
+                button("Find").on_press(AppMessage::BookingDetailsMessage(
+                    BookingDetailsMessage::FindGuests
+                )),
+            ]
+            .spacing(10)
+            .align_y(Center),
+            Column::with_children(found_guests).spacing(5),
+            row![
+                button("Save guests").on_press(AppMessage::BookingDetailsMessage(
+                    BookingDetailsMessage::SaveGuests
+                )),
+                button("Back").on_press(AppMessage::BookingDetailsMessage(
+                    BookingDetailsMessage::CloseAction
+                )),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10)
+        .into()
+    }
+}
+impl Screen for BookingDetailsScreen {
+    fn on_open(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.load_details(global_state)
+    }
+
+    fn update(
+        &mut self,
+        message: AppMessage,
+        global_state: Arc<Mutex<GlobalState>>,
+    ) -> Task<AppMessage> {
+        match message {
+            AppMessage::BookingDetailsMessage(m) => match m {
+                BookingDetailsMessage::RoomLoaded(room) => {
+                    self.rooms.insert(room.id, *room);
+                    Task::none()
+                }
+                BookingDetailsMessage::GuestLoaded(guest) => {
+                    self.guests.insert(guest.id, *guest);
+                    Task::none()
+                }
+                BookingDetailsMessage::BookingLoaded(booking) => {
+                    self.booking = *booking;
+                    self.action = None;
+                    self.load_details(global_state)
+                }
+                BookingDetailsMessage::OpenAction(action) => self.open_action(global_state, action),
+                BookingDetailsMessage::CloseAction => {
+                    self.action = None;
+                    self.error = "".to_owned();
+                    Task::none()
+                }
+                BookingDetailsMessage::ChangeCancelReason(x) => {
+                    self.cancel_reason_input.update(x);
+                    Task::none()
+                }
+                BookingDetailsMessage::ConfirmCancel => {
+                    if self.cancel_reason_input.get_text().trim().is_empty() {
+                        self.error = "Enter a cancellation reason".to_owned();
+                        return Task::none();
+                    }
+                    let input = CancelBookingInput {
+                        reason: self.cancel_reason_input.get_text().trim().to_owned(),
+                    };
+                    self.perform_change(
+                        cancel_booking(ApiClient::new(&global_state), self.booking.id, input),
+                        "Booking cancelled",
+                    )
+                }
+                BookingDetailsMessage::ToggleShowStartDate => {
+                    self.start_date_input.toggle_show();
+                    Task::none()
+                }
+                BookingDetailsMessage::ChangeStartDate(date) => {
+                    self.start_date_input.update_date(date);
+                    self.start_date_input.toggle_show();
+                    Task::none()
+                }
+                BookingDetailsMessage::ToggleShowEndDate => {
+                    self.end_date_input.toggle_show();
+                    Task::none()
+                }
+                BookingDetailsMessage::ChangeEndDate(date) => {
+                    self.end_date_input.update_date(date);
+                    self.end_date_input.toggle_show();
+                    Task::none()
+                }
+                BookingDetailsMessage::CheckAvailability => self.check_availability(global_state),
+                BookingDetailsMessage::AvailabilityChecked(available) => {
+                    if available {
+                        self.available_dates = Some((
+                            self.start_date_input.get_date(),
+                            self.end_date_input.get_date(),
+                        ));
+                    } else {
+                        self.error = "Room is occupied on some of these dates".to_owned();
+                    }
+                    Task::none()
+                }
+                BookingDetailsMessage::ConfirmDates => {
+                    let Some((start_date, end_date)) = self.available_dates else {
+                        return Task::none();
+                    };
+                    self.perform_change(
+                        change_booking_dates(
+                            ApiClient::new(&global_state),
+                            self.booking.id,
+                            ChangeBookingDatesInput::new(start_date, end_date),
+                        ),
+                        "Booking dates changed",
+                    )
+                }
+                BookingDetailsMessage::FreeRoomsFound(ids) => {
+                    let ids: Vec<_> = ids
+                        .into_iter()
+                        .filter(|id| *id != self.booking.room_id)
+                        .collect();
+                    let tasks: Vec<_> = ids
+                        .iter()
+                        .map(|id| self.load_room(&global_state, *id))
+                        .collect();
+                    self.free_room_ids = Some(ids);
+                    Task::batch(tasks)
+                }
+                BookingDetailsMessage::MoveToRoom(room_id) => self.perform_change(
+                    move_booking(
+                        ApiClient::new(&global_state),
+                        self.booking.id,
+                        MoveBookingInput { room_id },
+                    ),
+                    "Booking moved",
+                ),
+                BookingDetailsMessage::ChangeGuestSearch(x) => {
+                    self.guest_search_input.update(x);
+                    Task::none()
+                }
+                BookingDetailsMessage::FindGuests => {
+                    let search = self.guest_search_input.get_text().trim().to_owned();
+                    if search.is_empty() {
+                        return Task::none();
+                    }
+                    Task::perform(
+                        find_guests_matching_any(
+                            ApiClient::new(&global_state),
+                            search_queries(&search),
+                        ),
+                        |res| Self::map_result(res, BookingDetailsMessage::GuestsFound),
+                    )
+                }
+                BookingDetailsMessage::GuestsFound(ids) => {
+                    if ids.is_empty() {
+                        self.error = "No guests found".to_owned();
+                    }
+                    let task = self.load_guests(&global_state, ids.clone());
+                    self.found_guests = ids;
+                    task
+                }
+                BookingDetailsMessage::AddGuest(guest_id) => {
+                    if !self.edited_guests.contains(&guest_id) {
+                        self.edited_guests.push(guest_id);
+                    }
+                    Task::none()
+                }
+                BookingDetailsMessage::RemoveGuest(guest_id) => {
+                    self.edited_guests.retain(|id| *id != guest_id);
+                    Task::none()
+                }
+                BookingDetailsMessage::MakeMainGuest(guest_id) => {
+                    self.edited_guests.retain(|id| *id != guest_id);
+                    self.edited_guests.insert(0, guest_id);
+                    Task::none()
+                }
+                BookingDetailsMessage::SaveGuests => {
+                    self.error = "".to_owned();
+                    if let Err(err) = self.validate_guests() {
+                        self.error = err;
+                        return Task::none();
+                    }
+                    let Some((main_guest, other_guests)) = self.edited_guests.split_first() else {
+                        return Task::none();
+                    };
+                    let input = UpdateBookingGuestsInput {
+                        main_guest: *main_guest,
+                        other_guests: other_guests.to_vec(),
+                    };
+                    self.perform_change(
+                        update_booking_guests(
+                            ApiClient::new(&global_state),
+                            self.booking.id,
+                            input,
+                        ),
+                        "Booking guests updated",
+                    )
+                }
+                BookingDetailsMessage::BookingChanged(success_text) => Task::batch([
+                    Task::done(show_notification(success_text, NotificationType::Success)),
+                    self.reload_booking(global_state),
+                ]),
+                BookingDetailsMessage::SetError(err) => {
+                    self.error = err;
+                    Task::none()
+                }
+            },
+            _ => Task::none(),
+        }
+    }
+
+    fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<'_, AppMessage> {
+        let booking = &self.booking;
+        let room_text = self.rooms.get(&booking.room_id).map_or_else(
+            || "Loading ...".to_owned(),
+            |room| format!("{} (floor {})", room.room_number, room.floor),
+        );
+        let guests = Column::with_children(
+            self.booking
+                .guest_ids()
+                .into_iter()
+                .map(|guest_id| self.view_guest(guest_id)),
+        )
+        .spacing(5);
+        let action = match self.action {
+            None => self.view_actions(),
+            Some(BookingAction::Cancel) => self.view_cancel(),
+            Some(BookingAction::ChangeDates) => self.view_change_dates(),
+            Some(BookingAction::MoveRoom) => self.view_move_room(),
+            Some(BookingAction::EditGuests) => self.view_edit_guests(),
+        };
+
+        scrollable(
+            column![
+                text!("Booking")
+                    .size(TITLE_FONT_SIZE)
+                    .width(Fill)
+                    .align_x(Center),
+                Self::view_field("Room", room_text),
+                Self::view_field("Check-in", booking.start_date.to_string()),
+                Self::view_field("Check-out", booking.end_date.to_string()),
+                Self::view_field("Status", booking.status.to_string()),
+                self.view_price(),
+                text("Guests"),
+                guests,
+                action,
+                text!("{}", self.error).color(ERROR_COLOR).size(18),
+            ]
+            .spacing(FORM_SPACING)
+            .padding(FORM_PADDING),
+        )
+        .into()
+    }
+}
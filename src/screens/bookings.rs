@@ -0,0 +1,474 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    sync::{Arc, Mutex},
+};
+
+use iced::{
+    widget::{button, column, pick_list, row, text, text_input},
+    Alignment::Center,
+    Element,
+    Length::Fill,
+    Task,
+};
+use iced_aw::date_picker::Date;
+use uuid::Uuid;
+
+use crate::{
+    app::{AppMessage, GlobalState, Screen, ScreenType},
+    components::{
+        checkbox::Checkbox,
+        date_input::DateInput,
+        focus_chain::FocusChain,
+        list_input::booking_list_input::BookingListInput,
+        notification::NotificationType,
+        text_box::text_box::{TextBox, TextElement},
+    },
+    model::{booking::Booking, booking_status::BookingStatus, guest::Guest, room::Room},
+    services::{
+        api_client::{ApiClient, ApiResult},
+        find_bookings::{find_bookings, find_bookings_matching_any, FindBookingsInput},
+        find_guest::{find_guests_matching_any, search_queries},
+        find_rooms::find_rooms,
+        get_guest::get_guest,
+        get_room::get_room,
+    },
+    styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE},
+    utils::{add_days, is_date_before, show_notification},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoomFilter {
+    Any,
+    Only(Uuid, String),
+}
+impl Display for RoomFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoomFilter::Any => write!(f, "Any room"),
+            RoomFilter::Only(_, room_number) => write!(f, "Room {room_number}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusFilter {
+    Any,
+    Only(BookingStatus),
+}
+impl Display for StatusFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatusFilter::Any => write!(f, "Any status"),
+            StatusFilter::Only(status) => write!(f, "{status}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum BookingsMessage {
+    ChangeFilterByDates(bool),
+    ToggleShowFrom,
+    ChangeFrom(Date),
+    ToggleShowTo,
+    ChangeTo(Date),
+    ChangeRoomFilter(RoomFilter),
+    ChangeGuest(String),
+    ChangeStatusFilter(StatusFilter),
+    Search,
+    RoomsFound(Vec<Uuid>),
+    RoomLoaded(Box<Room>),
+    GuestsMatched(Vec<Uuid>),
+    BookingsFound(Vec<Uuid>),
+    BookingLoaded(Box<Booking>),
+    GuestLoaded(Box<Guest>),
+    ScrollBookings(f32),
+    OpenBooking(Uuid),
+    SetError(String),
+}
+
+const GUEST_ID: &str = "Bookings Guest";
+const STATUS_FILTERS: [StatusFilter; 5] = [
+    StatusFilter::Any,
+    StatusFilter::Only(BookingStatus::Booked),
+    StatusFilter::Only(BookingStatus::CheckedIn),
+    StatusFilter::Only(BookingStatus::CheckedOut),
+    StatusFilter::Only(BookingStatus::Cancelled),
+];
+
+pub struct BookingsScreen {
+    filter_by_dates_checkbox: Checkbox,
+    from_input: DateInput,
+    to_input: DateInput,
+    room_filter: RoomFilter,
+    guest_input: TextBox,
+    status_filter: StatusFilter,
+    booking_list: BookingListInput,
+    rooms: HashMap<Uuid, Room>,
+    guests: HashMap<Uuid, Guest>,
+    focus_chain: FocusChain,
+    error: String,
+}
+impl BookingsScreen {
+    pub fn new() -> Self {
+        let today = Date::today();
+
+        Self {
+            filter_by_dates_checkbox: Checkbox::new("Filter by dates", true),
+            from_input: DateInput::new(
+                "From",
+                today,
+                AppMessage::BookingsMessage(BookingsMessage::ToggleShowFrom),
+            ),
+            to_input: DateInput::new(
+                "To",
+                add_days(today, 7),
+                AppMessage::BookingsMessage(BookingsMessage::ToggleShowTo),
+            ),
+            room_filter: RoomFilter::Any,
+            guest_input: TextBox::new("", 40),
+            status_filter: StatusFilter::Any,
+            booking_list: BookingListInput::new(),
+            rooms: HashMap::new(),
+            guests: HashMap::new(),
+            focus_chain: FocusChain::new(vec![GUEST_ID]),
+            error: "".to_owned(),
+        }
+    }
+
+    fn map_result<T, F>(result: ApiResult<T>, on_success: F) -> AppMessage
+    where
+        F: FnOnce(T) -> BookingsMessage,
+    {
+        match result {
+            ApiResult::Success(ok) => AppMessage::BookingsMessage(on_success(ok)),
+            ApiResult::Forbidden => AppMessage::TokenExpired,
+            ApiResult::BadRequest(err) => {
+                AppMessage::BookingsMessage(BookingsMessage::SetError(err))
+            }
+            ApiResult::Error(err) => {
+                println!("Error loading bookings: {err}");
+                show_notification("Unexpected error", NotificationType::Error)
+            }
+        }
+    }
+
+    fn map_booking_result(result: ApiResult<Booking>) -> AppMessage {
+        Self::map_result(result, |booking| {
+            BookingsMessage::BookingLoaded(Box::new(booking))
+        })
+    }
+
+    fn get_bookings_input(&self) -> FindBookingsInput {
+        let (from, to) = if self.filter_by_dates_checkbox.is_checked() {
+            (
+                Some(self.from_input.get_date()),
+                Some(self.to_input.get_date()),
+            )
+        } else {
+            (None, None)
+        };
+        let room_id = match &self.room_filter {
+            RoomFilter::Any => None,
+            RoomFilter::Only(room_id, _) => Some(*room_id),
+        };
+
+        let status = match self.status_filter {
+            StatusFilter::Any => None,
+            StatusFilter::Only(status) => Some(status),
+        };
+
+        FindBookingsInput {
+            from,
+            to,
+            room_id,
+            status,
+            ..Default::default()
+        }
+    }
+
+    fn load_rooms(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        if !self.rooms.is_empty() {
+            return Task::none();
+        }
+
+        Task::perform(find_rooms(ApiClient::new(&global_state)), |res| {
+            Self::map_result(res, BookingsMessage::RoomsFound)
+        })
+    }
+
+    fn search(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.error = "".to_owned();
+        if self.filter_by_dates_checkbox.is_checked()
+            && is_date_before(self.to_input.get_date(), self.from_input.get_date())
+        {
+            self.error = "From date must not be after to date".to_owned();
+            return Task::none();
+        }
+        let guest = self.guest_input.get_text().trim().to_owned();
+        if !guest.is_empty() {
+            return Task::perform(
+                find_guests_matching_any(ApiClient::new(&global_state), search_queries(&guest)),
+                |res| Self::map_result(res, BookingsMessage::GuestsMatched),
+            );
+        }
+
+        Task::perform(
+            find_bookings(ApiClient::new(&global_state), self.get_bookings_input()),
+            |res| Self::map_result(res, BookingsMessage::BookingsFound),
+        )
+    }
+
+    fn search_guest_bookings(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        guest_ids: Vec<Uuid>,
+    ) -> Task<AppMessage> {
+        let base_input = self.get_bookings_input();
+        let inputs = guest_ids
+            .into_iter()
+            .map(|guest_id| FindBookingsInput {
+                guest_id: Some(guest_id),
+                ..base_input.clone()
+            })
+            .collect();
+
+        Task::perform(
+            find_bookings_matching_any(ApiClient::new(&global_state), inputs),
+            |res| Self::map_result(res, BookingsMessage::BookingsFound),
+        )
+    }
+
+    fn load_guest(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        booking: Booking,
+    ) -> Task<AppMessage> {
+        let guest_id = booking.main_guest;
+        self.booking_list.update_loaded(booking);
+        if self.guests.contains_key(&guest_id) {
+            return Task::none();
+        }
+
+        Task::perform(get_guest(ApiClient::new(&global_state), guest_id), |res| {
+            Self::map_result(res, |guest| BookingsMessage::GuestLoaded(Box::new(guest)))
+        })
+    }
+
+    fn view_booking<'a>(&'a self, booking: &'a Booking) -> Element<'a, AppMessage> {
+        let room_text = self.rooms.get(&booking.room_id).map_or_else(
+            || "Room ...".to_owned(),
+            |room| format!("Room {}", room.room_number),
+        );
+        let guest_text = self.guests.get(&booking.main_guest).map_or_else(
+            || "Loading guest ...".to_owned(),
+            |guest| format!("{} {}", guest.first_name, guest.last_name),
+        );
+
+        row![
+            column![
+                text!("{room_text}, {guest_text}"),
+                text!("{} - {}", booking.start_date, booking.end_date).size(14),
+            ]
+            .width(360),
+            text!("{}", booking.status).width(90),
+            button("Open").on_press(AppMessage::BookingsMessage(BookingsMessage::OpenBooking(
+                booking.id
+            ))),
+        ]
+        .spacing(10)
+        .align_y(Center)
+        .into()
+    }
+
+    fn view_filters(&self) -> Element<'_, AppMessage> {
+        let mut room_filters = vec![RoomFilter::Any];
+        let mut rooms: Vec<_> = self.rooms.values().collect();
+        rooms.sort_by(|a, b| a.room_number.cmp(&b.room_number));
+        room_filters.extend(
+            rooms
+                .into_iter()
+                .map(|room| RoomFilter::Only(room.id, room.room_number.clone())),
+        );
+
+        column![
+            self.filter_by_dates_checkbox
+                .view(|x| AppMessage::BookingsMessage(BookingsMessage::ChangeFilterByDates(x))),
+            row![
+                self.from_input
+                    .view(|x| AppMessage::BookingsMessage(BookingsMessage::ChangeFrom(x))),
+                self.to_input
+                    .view(|x| AppMessage::BookingsMessage(BookingsMessage::ChangeTo(x))),
+            ]
+            .spacing(10),
+            row![
+                pick_list(room_filters, Some(self.room_filter.clone()), |x| {
+                    AppMessage::BookingsMessage(BookingsMessage::ChangeRoomFilter(x))
+                }),
+                pick_list(STATUS_FILTERS, Some(self.status_filter), |x| {
+                    AppMessage::BookingsMessage(BookingsMessage::ChangeStatusFilter(x))
+                }),
+            ]
+            .spacing(10),
+            row![
+                text_input("Guest name or UCN", self.guest_input.get_text())
+                    .id(GUEST_ID)
+                    .on_input(|x| AppMessage::BookingsMessage(BookingsMessage::ChangeGuest(x)))
+                    .on_submit(AppMessage::BookingsMessage(BookingsMessage::Search))
+                    .width(250)
+                    .line_height(1.5),
+                button("Find")
+                    .on_press(AppMessage::BookingsMessage(BookingsMessage::Search))
+                    .height(30)
+                    .width(80),
+            ]
+            .spacing(10)
+            .align_y(Center),
+        ]
+        .spacing(10)
+        .align_x(Center)
+        .into()
+    }
+}
+impl Screen for BookingsScreen {
+    fn keep_alive(&self) -> bool {
+        true
+    }
+
+    fn on_open(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        Task::batch([
+            self.load_rooms(global_state.clone()),
+            self.search(global_state),
+        ])
+    }
+
+    fn update(
+        &mut self,
+        message: AppMessage,
+        global_state: Arc<Mutex<GlobalState>>,
+    ) -> Task<AppMessage> {
+        match message {
+            AppMessage::BookingsMessage(m) => match m {
+                BookingsMessage::ChangeFilterByDates(x) => {
+                    self.filter_by_dates_checkbox.update(x);
+                    Task::none()
+                }
+                BookingsMessage::ToggleShowFrom => {
+                    self.from_input.toggle_show();
+                    Task::none()
+                }
+                BookingsMessage::ChangeFrom(date) => {
+                    self.from_input.update_date(date);
+                    self.from_input.toggle_show();
+                    Task::none()
+                }
+                BookingsMessage::ToggleShowTo => {
+                    self.to_input.toggle_show();
+                    Task::none()
+                }
+                BookingsMessage::ChangeTo(date) => {
+                    self.to_input.update_date(date);
+                    self.to_input.toggle_show();
+                    Task::none()
+                }
+                BookingsMessage::ChangeRoomFilter(room_filter) => {
+                    self.room_filter = room_filter;
+                    self.search(global_state)
+                }
+                BookingsMessage::ChangeGuest(x) => {
+                    self.focus_chain.set_focus(Some(GUEST_ID));
+                    self.guest_input.update(x);
+                    Task::none()
+                }
+                BookingsMessage::ChangeStatusFilter(status_filter) => {
+                    self.status_filter = status_filter;
+                    self.search(global_state)
+                }
+                BookingsMessage::Search => self.search(global_state),
+                BookingsMessage::RoomsFound(ids) => Task::batch(ids.into_iter().map(|id| {
+                    Task::perform(get_room(ApiClient::new(&global_state), id), |res| {
+                        Self::map_result(res, |room| BookingsMessage::RoomLoaded(Box::new(room)))
+                    })
+                })),
+                BookingsMessage::RoomLoaded(room) => {
+                    self.rooms.insert(room.id, *room);
+                    Task::none()
+                }
+                BookingsMessage::GuestsMatched(ids) => {
+                    if ids.is_empty() {
+                        return self.booking_list.update_ids(
+                            global_state,
+                            vec![],
+                            Self::map_booking_result,
+                        );
+                    }
+                    self.search_guest_bookings(global_state, ids)
+                }
+                BookingsMessage::BookingsFound(ids) => {
+                    self.booking_list
+                        .update_ids(global_state, ids, Self::map_booking_result)
+                }
+                BookingsMessage::BookingLoaded(booking) => self.load_guest(global_state, *booking),
+                BookingsMessage::GuestLoaded(guest) => {
+                    self.guests.insert(guest.id, *guest);
+                    Task::none()
+                }
+                BookingsMessage::ScrollBookings(scroll_amount) => self.booking_list.load_scrolled(
+                    global_state,
+                    scroll_amount,
+                    Self::map_booking_result,
+                ),
+                BookingsMessage::OpenBooking(booking_id) => {
+                    match self.booking_list.get_loaded(booking_id) {
+                        Some(booking) => Task::done(AppMessage::NavigateTo(
+                            ScreenType::BookingDetails(Box::new(booking.clone())),
+                        )),
+                        None => Task::none(),
+                    }
+                }
+                BookingsMessage::SetError(err) => {
+                    self.error = err;
+                    Task::none()
+                }
+            },
+            AppMessage::SelectNext => {
+                self.focus_chain.set_next();
+                self.focus_chain.apply_focus()
+            }
+            AppMessage::SelectPrev => {
+                self.focus_chain.set_prev();
+                self.focus_chain.apply_focus()
+            }
+            _ => Task::none(),
+        }
+    }
+
+    fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<'_, AppMessage> {
+        let results: Element<'_, AppMessage> = if self.booking_list.is_empty() {
+            text("No bookings found").into()
+        } else {
+            self.booking_list.view(
+                |booking| self.view_booking(booking),
+                |x| {
+                    AppMessage::BookingsMessage(BookingsMessage::ScrollBookings(
+                        x.relative_offset().y,
+                    ))
+                },
+            )
+        };
+
+        column![
+            text!("Bookings")
+                .align_x(Center)
+                .size(TITLE_FONT_SIZE)
+                .width(Fill),
+            self.view_filters(),
+            text!("{}", self.error).color(ERROR_COLOR).size(18),
+            results,
+        ]
+        .spacing(FORM_SPACING)
+        .align_x(Center)
+        .padding(FORM_PADDING)
+        .into()
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    sync::{Arc, Mutex},
+};
+
+use iced::{
+    widget::{button, column, pick_list, row, scrollable, text, Column},
+    Alignment::Center,
+    Element,
+    Length::Fill,
+    Task,
+};
+use iced_aw::date_picker::Date;
+use uuid::Uuid;
+
+use crate::{
+    app::{AppMessage, GlobalState, Screen, ScreenType},
+    components::notification::NotificationType,
+    model::{guest::Guest, phone_number::format_phone_number},
+    services::{
+        api_client::{ApiClient, ApiResult},
+        find_guest::{find_guest, FindGuestInput},
+        get_guest::get_guest,
+    },
+    styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE, WARNING_COLOR},
+    utils::{add_days, is_date_before, show_notification},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpiryWindow(i64);
+impl Display for ExpiryWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Within {} days", self.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ExpiringDocumentsMessage {
+    ChangeWindow(ExpiryWindow),
+    Refresh,
+    GuestsFound(Vec<Uuid>),
+    GuestLoaded(Box<Guest>),
+    GuestLoadFailed,
+    EditGuest(Uuid),
+    SetError(String),
+}
+
+const EXPIRY_WINDOWS: [ExpiryWindow; 4] = [
+    ExpiryWindow(7),
+    ExpiryWindow(30),
+    ExpiryWindow(90),
+    ExpiryWindow(365),
+];
+
+pub struct ExpiringDocumentsScreen {
+    window: ExpiryWindow,
+    guest_ids: Option<Vec<Uuid>>,
+    guests: HashMap<Uuid, Guest>,
+    failed_guests: usize,
+    error: String,
+}
+impl ExpiringDocumentsScreen {
+    pub fn new() -> Self {
+        Self {
+            window: ExpiryWindow(30),
+            guest_ids: None,
+            guests: HashMap::new(),
+            failed_guests: 0,
+            error: "".to_owned(),
+        }
+    }
+
+    fn map_result<T, F>(result: ApiResult<T>, on_success: F) -> AppMessage
+    where
+        F: FnOnce(T) -> ExpiringDocumentsMessage,
+    {
+        match result {
+            ApiResult::Success(ok) => AppMessage::ExpiringDocumentsMessage(on_success(ok)),
+            ApiResult::Forbidden => AppMessage::TokenExpired,
+            ApiResult::BadRequest(err) => {
+                AppMessage::ExpiringDocumentsMessage(ExpiringDocumentsMessage::SetError(err))
+            }
+            ApiResult::Error(err) => {
+                println!("Error loading guests: {err}");
+                show_notification("Unexpected error", NotificationType::Error)
+            }
+        }
+    }
+
+    fn refresh(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.error = "".to_owned();
+        self.guests.clear();
+        self.failed_guests = 0;
+        self.guest_ids = None;
+        // Queried for the widest window, narrower windows are filtered locally
+        let max_window = EXPIRY_WINDOWS.iter().map(|window| window.0).max();
+        let input = FindGuestInput {
+            id_card_valid_before: Some(
+                add_days(Date::today(), max_window.unwrap_or_default()).to_string(),
+            ),
+            ..Default::default()
+        };
+
+        Task::perform(find_guest(ApiClient::new(&global_state), input), |res| {
+            Self::map_result(res, ExpiringDocumentsMessage::GuestsFound)
+        })
+    }
+
+    fn load_guests(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        ids: Vec<Uuid>,
+    ) -> Task<AppMessage> {
+        let tasks: Vec<_> = ids
+            .iter()
+            .map(|id| {
+                Task::perform(
+                    get_guest(ApiClient::new(&global_state), *id),
+                    |res| match res {
+                        ApiResult::Success(guest) => AppMessage::ExpiringDocumentsMessage(
+                            ExpiringDocumentsMessage::GuestLoaded(Box::new(guest)),
+                        ),
+                        ApiResult::Forbidden => AppMessage::TokenExpired,
+                        ApiResult::BadRequest(err) | ApiResult::Error(err) => {
+                            println!("Error loading guest: {err}");
+                            AppMessage::ExpiringDocumentsMessage(
+                                ExpiringDocumentsMessage::GuestLoadFailed,
+                            )
+                        }
+                    },
+                )
+            })
+            .collect();
+        self.guest_ids = Some(ids);
+
+        Task::batch(tasks)
+    }
+
+    fn get_expiring_guests(&self) -> Vec<&Guest> {
+        let limit = add_days(Date::today(), self.window.0);
+        let mut expiring: Vec<_> = self
+            .guests
+            .values()
+            .filter(|guest| {
+                guest
+                    .id_card
+                    .as_ref()
+                    .is_some_and(|id_card| is_date_before(id_card.validity_date, limit))
+            })
+            .collect();
+        expiring.sort_by_key(|guest| {
+            guest.id_card.as_ref().map(|id_card| {
+                let date = id_card.validity_date;
+                (date.year, date.month, date.day)
+            })
+        });
+
+        expiring
+    }
+
+    fn view_guest(guest: &Guest) -> Element<'_, AppMessage> {
+        let Some(id_card) = &guest.id_card else {
+            return column![].into();
+        };
+        let today = Date::today();
+        let status = if is_date_before(id_card.validity_date, today) {
+            text!("Expired {}", id_card.validity_date).color(ERROR_COLOR)
+        } else {
+            text!("Expires {}", id_card.validity_date).color(WARNING_COLOR)
+        };
+
+        row![
+            column![
+                text!("{} {}", guest.first_name, guest.last_name),
+                text!(
+                    "{} {}, {}",
+                    id_card.document_type,
+                    id_card.id_card_number,
+                    guest
+                        .phone_number
+                        .as_deref()
+                        .map_or("no phone".to_owned(), format_phone_number)
+                )
+                .size(14),
+            ]
+            .width(300),
+            status.width(160),
+            button("Edit").on_press(AppMessage::ExpiringDocumentsMessage(
+                ExpiringDocumentsMessage::EditGuest(guest.id)
+            )),
+        ]
+        .spacing(10)
+        .align_y(Center)
+        .into()
+    }
+
+    fn view_report(&self) -> Element<'_, AppMessage> {
+        let Some(ids) = &self.guest_ids else {
+            return text("Loading ...").into();
+        };
+        let done = self.guests.len() + self.failed_guests;
+        if done < ids.len() {
+            return text!("Loading guests {done}/{} ...", ids.len()).into();
+        }
+
+        let failed = (self.failed_guests > 0)
+            .then(|| text!("{} guests could not be loaded", self.failed_guests).color(ERROR_COLOR));
+        let expiring = self.get_expiring_guests();
+        if expiring.is_empty() {
+            return column![text("No documents expiring in this window")]
+                .push_maybe(failed)
+                .spacing(10)
+                .into();
+        }
+
+        column![
+            text!("{} guests need updated documents", expiring.len()),
+            Column::with_children(expiring.into_iter().map(Self::view_guest)).spacing(10),
+        ]
+        .push_maybe(failed)
+        .spacing(10)
+        .into()
+    }
+}
+impl Screen for ExpiringDocumentsScreen {
+    fn on_open(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.refresh(global_state)
+    }
+
+    fn update(
+        &mut self,
+        message: AppMessage,
+        global_state: Arc<Mutex<GlobalState>>,
+    ) -> Task<AppMessage> {
+        match message {
+            AppMessage::ExpiringDocumentsMessage(m) => match m {
+                ExpiringDocumentsMessage::ChangeWindow(window) => {
+                    self.window = window;
+                    Task::none()
+                }
+                ExpiringDocumentsMessage::Refresh => self.refresh(global_state),
+                ExpiringDocumentsMessage::GuestsFound(ids) => self.load_guests(global_state, ids),
+                ExpiringDocumentsMessage::GuestLoaded(guest) => {
+                    self.guests.insert(guest.id, *guest);
+                    Task::none()
+                }
+                ExpiringDocumentsMessage::GuestLoadFailed => {
+                    self.failed_guests += 1;
+                    Task::none()
+                }
+                ExpiringDocumentsMessage::EditGuest(guest_id) => match self.guests.get(&guest_id) {
+                    Some(guest) => Task::done(AppMessage::NavigateTo(ScreenType::EditGuest(
+                        Box::new(guest.clone()),
+                    ))),
+                    None => Task::none(),
+                },
+                ExpiringDocumentsMessage::SetError(err) => {
+                    self.error = err;
+                    Task::none()
+                }
+            },
+            _ => Task::none(),
+        }
+    }
+
+    fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<'_, AppMessage> {
+        scrollable(
+            column![
+                text!("Expiring documents")
+                    .size(TITLE_FONT_SIZE)
+                    .width(Fill)
+                    .align_x(Center),
+                row![
+                    pick_list(EXPIRY_WINDOWS, Some(self.window), |x| {
+                        AppMessage::ExpiringDocumentsMessage(
+                            ExpiringDocumentsMessage::ChangeWindow(x),
+                        )
+                    }),
+                    button("Refresh").on_press(AppMessage::ExpiringDocumentsMessage(
+                        ExpiringDocumentsMessage::Refresh
+                    )),
+                ]
+                .spacing(10),
+                self.view_report(),
+                text!("{}", self.error).color(ERROR_COLOR).size(18),
+            ]
+            .spacing(FORM_SPACING)
+            .padding(FORM_PADDING)
+            .align_x(Center),
+        )
+        .into()
+    }
+}
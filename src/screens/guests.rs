@@ -0,0 +1,486 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use iced::{
+    border::Radius,
+    widget::{
+        button, column, container, container::Style, row, scrollable, text, text_input, Column,
+    },
+    Alignment::Center,
+    Border, Element,
+    Length::Fill,
+    Task, Theme,
+};
+use iced_aw::date_picker::Date;
+use uuid::Uuid;
+
+use crate::{
+    app::{AppMessage, GlobalState, Screen, ScreenType},
+    components::{
+        checkbox::Checkbox,
+        date_input::DateInput,
+        focus_chain::FocusChain,
+        notification::NotificationType,
+        text_box::{
+            phone_number_text_box::PhoneNumberTextBox,
+            text_box::{TextBox, TextElement},
+            ucn_text_box::UcnTextBox,
+        },
+    },
+    model::{guest::Guest, phone_number::format_phone_number},
+    services::{
+        api_client::{ApiClient, ApiResult},
+        find_guest::{find_guest, FindGuestInput},
+        get_guest::get_guest,
+    },
+    styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE, WARNING_COLOR},
+    utils::show_notification,
+};
+
+#[derive(Debug, Clone)]
+pub enum GuestsMessage {
+    ChangeFirstName(String),
+    ChangeLastName(String),
+    ChangeUcn(String),
+    ChangePhoneNumber(String),
+    ChangeSearchByDateOfBirth(bool),
+    ChangeDateOfBirth(Date),
+    ToggleShowDateOfBirth,
+    Search,
+    GuestsFound(Vec<Uuid>),
+    GuestLoaded(Box<Guest>),
+    SetPage(usize),
+    SelectGuest(Uuid),
+    EditGuest,
+    SetError(String),
+}
+
+const FIRST_NAME_ID: &str = "Guests First Name";
+const LAST_NAME_ID: &str = "Guests Last Name";
+const UCN_ID: &str = "Guests UCN";
+const PHONE_NUMBER_ID: &str = "Guests Phone Number";
+const SEARCH_IDS: [&str; 4] = [FIRST_NAME_ID, LAST_NAME_ID, UCN_ID, PHONE_NUMBER_ID];
+
+const PAGE_SIZE: usize = 10;
+const PANEL_WIDTH: u16 = 360;
+
+pub struct GuestsScreen {
+    first_name_input: TextBox,
+    last_name_input: TextBox,
+    ucn_input: UcnTextBox,
+    phone_number_input: PhoneNumberTextBox,
+    search_by_date_of_birth_checkbox: Checkbox,
+    date_of_birth_input: DateInput,
+    guest_ids: Option<Vec<Uuid>>,
+    guests: HashMap<Uuid, Guest>,
+    page: usize,
+    selected: Option<Uuid>,
+    focus_chain: FocusChain,
+    error: String,
+}
+impl GuestsScreen {
+    pub fn new() -> Self {
+        Self {
+            first_name_input: TextBox::new("", 20),
+            last_name_input: TextBox::new("", 20),
+            ucn_input: UcnTextBox::new(""),
+            phone_number_input: PhoneNumberTextBox::new(""),
+            search_by_date_of_birth_checkbox: Checkbox::new("Search by date of birth", false),
+            date_of_birth_input: DateInput::new(
+                "Date of birth",
+                Date::today(),
+                AppMessage::GuestsMessage(GuestsMessage::ToggleShowDateOfBirth),
+            ),
+            guest_ids: None,
+            guests: HashMap::new(),
+            page: 0,
+            selected: None,
+            focus_chain: FocusChain::new(SEARCH_IDS.into()),
+            error: "".to_owned(),
+        }
+    }
+
+    fn map_result<T, F>(result: ApiResult<T>, on_success: F) -> AppMessage
+    where
+        F: FnOnce(T) -> GuestsMessage,
+    {
+        match result {
+            ApiResult::Success(ok) => AppMessage::GuestsMessage(on_success(ok)),
+            ApiResult::Forbidden => AppMessage::TokenExpired,
+            ApiResult::BadRequest(err) => AppMessage::GuestsMessage(GuestsMessage::SetError(err)),
+            ApiResult::Error(err) => {
+                println!("Error loading guests: {err}");
+                show_notification("Unexpected error", NotificationType::Error)
+            }
+        }
+    }
+
+    fn get_text_if_not_empty(input: &impl TextElement) -> Option<String> {
+        if input.get_text().is_empty() {
+            None
+        } else {
+            Some(input.get_text().to_owned())
+        }
+    }
+
+    fn get_find_guests_input(&self) -> Result<FindGuestInput, String> {
+        let date_of_birth = if self.search_by_date_of_birth_checkbox.is_checked() {
+            Some(self.date_of_birth_input.get_date().to_string())
+        } else {
+            None
+        };
+
+        Ok(FindGuestInput {
+            first_name: Self::get_text_if_not_empty(&self.first_name_input),
+            last_name: Self::get_text_if_not_empty(&self.last_name_input),
+            date_of_birth,
+            ucn: Self::get_text_if_not_empty(&self.ucn_input),
+            phone_number: self.phone_number_input.get_normalized()?,
+            ..Default::default()
+        })
+    }
+
+    fn search(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.error = "".to_owned();
+        let input = match self.get_find_guests_input() {
+            Ok(ok) => ok,
+            Err(err) => {
+                self.error = err;
+                return Task::none();
+            }
+        };
+
+        Task::perform(find_guest(ApiClient::new(&global_state), input), |res| {
+            Self::map_result(res, GuestsMessage::GuestsFound)
+        })
+    }
+
+    fn page_count(&self) -> usize {
+        self.guest_ids
+            .as_ref()
+            .map_or(0, |ids| ids.len().div_ceil(PAGE_SIZE))
+    }
+
+    fn page_ids(&self) -> &[Uuid] {
+        let Some(ids) = &self.guest_ids else {
+            return &[];
+        };
+        let start = (self.page * PAGE_SIZE).min(ids.len());
+        let end = (start + PAGE_SIZE).min(ids.len());
+
+        &ids[start..end]
+    }
+
+    fn load_guest(global_state: &Arc<Mutex<GlobalState>>, guest_id: Uuid) -> Task<AppMessage> {
+        Task::perform(get_guest(ApiClient::new(global_state), guest_id), |res| {
+            Self::map_result(res, |guest| GuestsMessage::GuestLoaded(Box::new(guest)))
+        })
+    }
+
+    fn load_page(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        page: usize,
+    ) -> Task<AppMessage> {
+        self.page = page.min(self.page_count().saturating_sub(1));
+        let tasks: Vec<_> = self
+            .page_ids()
+            .iter()
+            .filter(|id| !self.guests.contains_key(id))
+            .map(|id| Self::load_guest(&global_state, *id))
+            .collect();
+
+        Task::batch(tasks)
+    }
+
+    fn get_panel_style(theme: &Theme) -> Style {
+        let color = theme.palette().primary;
+
+        Style {
+            border: Border {
+                color,
+                width: 2.0,
+                radius: Radius::new(4),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn view_search(&self) -> Element<'_, AppMessage> {
+        column![
+            row![
+                text_input("First name", self.first_name_input.get_text())
+                    .id(FIRST_NAME_ID)
+                    .on_input(|x| AppMessage::GuestsMessage(GuestsMessage::ChangeFirstName(x)))
+                    .on_submit(AppMessage::GuestsMessage(GuestsMessage::Search))
+                    .width(150)
+                    .line_height(1.5),
+                text_input("Last name", self.last_name_input.get_text())
+                    .id(LAST_NAME_ID)
+                    .on_input(|x| AppMessage::GuestsMessage(GuestsMessage::ChangeLastName(x)))
+                    .on_submit(AppMessage::GuestsMessage(GuestsMessage::Search))
+                    .width(150)
+                    .line_height(1.5),
+            ]
+            .spacing(10),
+            row![
+                text_input("UCN", self.ucn_input.get_text())
+                    .id(UCN_ID)
+                    .on_input(|x| AppMessage::GuestsMessage(GuestsMessage::ChangeUcn(x)))
+                    .on_submit(AppMessage::GuestsMessage(GuestsMessage::Search))
+                    .width(150)
+                    .line_height(1.5),
+                text_input("Phone number", self.phone_number_input.get_text())
+                    .id(PHONE_NUMBER_ID)
+                    .on_input(|x| AppMessage::GuestsMessage(GuestsMessage::ChangePhoneNumber(x)))
+                    .on_submit(AppMessage::GuestsMessage(GuestsMessage::Search))
+                    .width(150)
+                    .line_height(1.5),
+            ]
+            .spacing(10),
+            self.search_by_date_of_birth_checkbox
+                .view(|x| AppMessage::GuestsMessage(GuestsMessage::ChangeSearchByDateOfBirth(x))),
+            self.date_of_birth_input
+                .view(|x| AppMessage::GuestsMessage(GuestsMessage::ChangeDateOfBirth(x))),
+            button("Find")
+                .on_press(AppMessage::GuestsMessage(GuestsMessage::Search))
+                .height(30)
+                .width(80),
+        ]
+        .spacing(10)
+        .align_x(Center)
+        .into()
+    }
+
+    fn view_results(&self) -> Element<'_, AppMessage> {
+        let Some(ids) = &self.guest_ids else {
+            return column![].into();
+        };
+        if ids.is_empty() {
+            return text("No guests found").into();
+        }
+
+        let guests = Column::with_children(self.page_ids().iter().map(|id| {
+            let label = self.guests.get(id).map_or_else(
+                || "Loading ...".to_owned(),
+                |guest| {
+                    format!(
+                        "{} {}, {}",
+                        guest.first_name, guest.last_name, guest.date_of_birth
+                    )
+                },
+            );
+            let select_button = if self.selected == Some(*id) {
+                button("Selected")
+            } else {
+                button("Select")
+                    .on_press(AppMessage::GuestsMessage(GuestsMessage::SelectGuest(*id)))
+            };
+
+            row![text(label).width(260), select_button]
+                .spacing(10)
+                .align_y(Center)
+                .into()
+        }))
+        .spacing(5);
+
+        let page_count = self.page_count();
+        let pages = row![
+            button("Previous").on_press_maybe((self.page > 0).then_some(
+                AppMessage::GuestsMessage(GuestsMessage::SetPage(self.page.saturating_sub(1)))
+            )),
+            text!(
+                "Page {} of {page_count} ({} guests)",
+                self.page + 1,
+                ids.len()
+            ),
+            button("Next").on_press_maybe((self.page + 1 < page_count).then_some(
+                AppMessage::GuestsMessage(GuestsMessage::SetPage(self.page + 1))
+            )),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        column![guests, pages].spacing(10).into()
+    }
+
+    fn view_field<'a>(label: &'a str, value: String) -> Element<'a, AppMessage> {
+        row![text(label).width(130), text(value)].spacing(10).into()
+    }
+
+    fn view_details(&self) -> Option<Element<'_, AppMessage>> {
+        let guest = self.selected.and_then(|id| self.guests.get(&id))?;
+
+        let mut details = column![
+            text!("{} {}", guest.first_name, guest.last_name).size(TITLE_FONT_SIZE),
+            Self::view_field("Date of birth", guest.date_of_birth.to_string()),
+        ]
+        .spacing(5);
+
+        let warning = guest
+            .get_document_warning(Date::today())
+            .map(|warning| text(warning).color(WARNING_COLOR));
+        details = details.push_maybe(warning);
+
+        details = details.push(Self::view_field(
+            "Phone number",
+            guest
+                .phone_number
+                .as_deref()
+                .map_or("-".to_owned(), format_phone_number),
+        ));
+
+        details = match &guest.id_card {
+            Some(id_card) => details
+                .push(Self::view_field(
+                    "Document",
+                    id_card.document_type.to_string(),
+                ))
+                .push(Self::view_field("UCN", id_card.ucn.clone()))
+                .push(Self::view_field(
+                    "Document number",
+                    id_card.id_card_number.clone(),
+                ))
+                .push(Self::view_field("Nationality", id_card.nationality.clone()))
+                .push(Self::view_field(
+                    "Issuing country",
+                    id_card.issuing_country.clone(),
+                ))
+                .push(Self::view_field(
+                    "Issued by",
+                    id_card.issue_authority.clone(),
+                ))
+                .push(Self::view_field(
+                    "Issued on",
+                    id_card.issue_date.to_string(),
+                ))
+                .push(Self::view_field(
+                    "Valid until",
+                    id_card.validity_date.to_string(),
+                )),
+            None => details.push(Self::view_field("Document", "-".to_owned())),
+        };
+
+        Some(
+            container(details.push(
+                button("Edit").on_press(AppMessage::GuestsMessage(GuestsMessage::EditGuest)),
+            ))
+            .width(PANEL_WIDTH)
+            .padding(10)
+            .style(Self::get_panel_style)
+            .into(),
+        )
+    }
+}
+impl Screen for GuestsScreen {
+    fn keep_alive(&self) -> bool {
+        true
+    }
+
+    fn on_open(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        // Guests may have been edited while this screen was in the history
+        self.guests.clear();
+        self.load_page(global_state, self.page)
+    }
+
+    fn update(
+        &mut self,
+        message: AppMessage,
+        global_state: Arc<Mutex<GlobalState>>,
+    ) -> Task<AppMessage> {
+        match message {
+            AppMessage::GuestsMessage(m) => match m {
+                GuestsMessage::ChangeFirstName(first_name) => {
+                    self.focus_chain.set_focus(Some(FIRST_NAME_ID));
+                    self.first_name_input.update(first_name);
+                    Task::none()
+                }
+                GuestsMessage::ChangeLastName(last_name) => {
+                    self.focus_chain.set_focus(Some(LAST_NAME_ID));
+                    self.last_name_input.update(last_name);
+                    Task::none()
+                }
+                GuestsMessage::ChangeUcn(ucn) => {
+                    self.focus_chain.set_focus(Some(UCN_ID));
+                    self.ucn_input.update(ucn);
+                    Task::none()
+                }
+                GuestsMessage::ChangePhoneNumber(phone_number) => {
+                    self.focus_chain.set_focus(Some(PHONE_NUMBER_ID));
+                    self.phone_number_input.update(phone_number);
+                    Task::none()
+                }
+                GuestsMessage::ChangeSearchByDateOfBirth(should_search) => {
+                    self.search_by_date_of_birth_checkbox.update(should_search);
+                    Task::none()
+                }
+                GuestsMessage::ChangeDateOfBirth(date) => {
+                    self.date_of_birth_input.update_date(date);
+                    self.date_of_birth_input.toggle_show();
+                    Task::none()
+                }
+                GuestsMessage::ToggleShowDateOfBirth => {
+                    self.date_of_birth_input.toggle_show();
+                    Task::none()
+                }
+                GuestsMessage::Search => self.search(global_state),
+                GuestsMessage::GuestsFound(ids) => {
+                    self.guest_ids = Some(ids);
+                    self.selected = None;
+                    self.load_page(global_state, 0)
+                }
+                GuestsMessage::GuestLoaded(guest) => {
+                    self.guests.insert(guest.id, *guest);
+                    Task::none()
+                }
+                GuestsMessage::SetPage(page) => self.load_page(global_state, page),
+                GuestsMessage::SelectGuest(guest_id) => {
+                    self.selected = Some(guest_id);
+                    Task::none()
+                }
+                GuestsMessage::EditGuest => match self.selected.and_then(|id| self.guests.get(&id))
+                {
+                    Some(guest) => Task::done(AppMessage::NavigateTo(ScreenType::EditGuest(
+                        Box::new(guest.clone()),
+                    ))),
+                    None => Task::none(),
+                },
+                GuestsMessage::SetError(err) => {
+                    self.error = err;
+                    Task::none()
+                }
+            },
+            AppMessage::SelectNext => {
+                self.focus_chain.set_next();
+                self.focus_chain.apply_focus()
+            }
+            AppMessage::SelectPrev => {
+                self.focus_chain.set_prev();
+                self.focus_chain.apply_focus()
+            }
+            _ => Task::none(),
+        }
+    }
+
+    fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<'_, AppMessage> {
+        scrollable(
+            column![
+                text!("Guests")
+                    .size(TITLE_FONT_SIZE)
+                    .width(Fill)
+                    .align_x(Center),
+                self.view_search(),
+                text!("{}", self.error).color(ERROR_COLOR).size(18),
+                row![self.view_results()]
+                    .push_maybe(self.view_details())
+                    .spacing(FORM_SPACING),
+            ]
+            .spacing(FORM_SPACING)
+            .padding(FORM_PADDING)
+            .align_x(Center),
+        )
+        .into()
+    }
+}
@@ -1,40 +1,385 @@
 use std::sync::{Arc, Mutex};
 
 use iced::{
-    widget::{column, text},
+    border::Radius,
+    widget::{button, column, container, container::Style, scrollable, text, Column, Row},
     Alignment::Center,
-    Element,
+    Border, Element,
     Length::Fill,
-    Task,
+    Task, Theme,
 };
+use iced_aw::date_picker::Date;
+use uuid::Uuid;
 
 use crate::{
-    app::{AppMessage, GlobalState, Screen},
-    styles::{FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE},
+    app::{AppMessage, GlobalState, Screen, ScreenType},
+    model::{booking_status::BookingStatus, guest::Guest, room::Room},
+    security::Role,
+    services::{
+        api_client::{ApiClient, ApiResult},
+        find_bookings::{find_bookings, find_bookings_matching_any, FindBookingsInput},
+        find_unoccupied_rooms::{find_unoccupied_rooms, FindUnoccupiedRoomsInput, RoomFilters},
+        get_guest::get_guest,
+        get_room::get_room,
+    },
+    styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE},
+    utils::add_days,
 };
 
-pub struct HomeScreen;
+#[derive(Debug, Clone)]
+pub enum HomeMessage {
+    Refresh,
+    ArrivalsLoaded(Vec<Uuid>),
+    DeparturesLoaded(Vec<Uuid>),
+    OccupiedLoaded(Vec<Uuid>),
+    FreeRoomsLoaded(Vec<Uuid>),
+    FreeRoomLoaded(Box<Room>),
+    RecentGuestLoaded(Box<Guest>),
+    SetError(String),
+}
+
+const TILE_WIDTH: u16 = 220;
+const TILE_VALUE_SIZE: u16 = 28;
+const TILE_LIST_SIZE: usize = 5;
+
+pub struct HomeScreen {
+    arrivals: Option<usize>,
+    departures: Option<usize>,
+    occupied_rooms: Option<usize>,
+    free_room_ids: Option<Vec<Uuid>>,
+    free_rooms: Vec<Room>,
+    recent_guest_ids: Vec<Uuid>,
+    recent_guests: Vec<Guest>,
+    error: String,
+}
 impl HomeScreen {
     pub fn new() -> Self {
-        Self
+        Self {
+            arrivals: None,
+            departures: None,
+            occupied_rooms: None,
+            free_room_ids: None,
+            free_rooms: Vec::new(),
+            recent_guest_ids: Vec::new(),
+            recent_guests: Vec::new(),
+            error: "".to_owned(),
+        }
+    }
+
+    fn map_result<T, F>(result: ApiResult<T>, on_success: F) -> AppMessage
+    where
+        F: FnOnce(T) -> HomeMessage,
+    {
+        match result {
+            ApiResult::Success(ok) => AppMessage::HomeMessage(on_success(ok)),
+            ApiResult::Forbidden => AppMessage::TokenExpired,
+            ApiResult::BadRequest(err) => AppMessage::HomeMessage(HomeMessage::SetError(err)),
+            ApiResult::Error(err) => {
+                println!("Error loading dashboard: {err}");
+                AppMessage::HomeMessage(HomeMessage::SetError("Unexpected error".to_owned()))
+            }
+        }
+    }
+
+    fn find_bookings_task<F>(
+        global_state: &Arc<Mutex<GlobalState>>,
+        input: FindBookingsInput,
+        on_success: F,
+    ) -> Task<AppMessage>
+    where
+        F: Fn(Vec<Uuid>) -> HomeMessage + Send + 'static,
+    {
+        Task::perform(
+            find_bookings(ApiClient::new(global_state), input),
+            move |res| Self::map_result(res, &on_success),
+        )
+    }
+
+    fn get_role(global_state: &Arc<Mutex<GlobalState>>) -> Option<Role> {
+        global_state
+            .lock()
+            .unwrap()
+            .token
+            .as_ref()
+            .map(|token| token.role)
+    }
+
+    fn refresh(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        let today = Date::today();
+        let tomorrow = add_days(today, 1);
+        // Users may only query their own bookings, staff wide queries are forbidden for them
+        let is_staff = Self::get_role(&global_state) == Some(Role::Admin);
+
+        self.error = "".to_owned();
+
+        let arrivals = FindBookingsInput {
+            starts_on: Some(today),
+            status: Some(BookingStatus::Booked),
+            mine: !is_staff,
+            ..Default::default()
+        };
+        let departures = FindBookingsInput {
+            ends_on: Some(today),
+            status: Some(BookingStatus::CheckedIn),
+            mine: !is_staff,
+            ..Default::default()
+        };
+        let booking_tasks = [
+            Self::find_bookings_task(&global_state, arrivals, HomeMessage::ArrivalsLoaded),
+            Self::find_bookings_task(&global_state, departures, HomeMessage::DeparturesLoaded),
+        ];
+        if !is_staff {
+            return Task::batch(booking_tasks);
+        }
+
+        self.recent_guest_ids = global_state.lock().unwrap().recent_guests.clone();
+        self.recent_guests
+            .retain(|guest| self.recent_guest_ids.contains(&guest.id));
+
+        let occupied =
+            [BookingStatus::Booked, BookingStatus::CheckedIn].map(|status| FindBookingsInput {
+                from: Some(today),
+                to: Some(tomorrow),
+                status: Some(status),
+                ..Default::default()
+            });
+        let free_rooms = FindUnoccupiedRoomsInput {
+            start_date: today,
+            end_date: tomorrow,
+            minimum_capacity: None,
+            maximum_capacity: None,
+            filters: RoomFilters::default(),
+        };
+
+        let guest_tasks = self.recent_guest_ids.iter().map(|id| {
+            Task::perform(get_guest(ApiClient::new(&global_state), *id), |res| {
+                Self::map_result(res, |guest| HomeMessage::RecentGuestLoaded(Box::new(guest)))
+            })
+        });
+
+        Task::batch(
+            booking_tasks
+                .into_iter()
+                .chain([
+                    Task::perform(
+                        find_bookings_matching_any(
+                            ApiClient::new(&global_state),
+                            occupied.to_vec(),
+                        ),
+                        |res| Self::map_result(res, HomeMessage::OccupiedLoaded),
+                    ),
+                    Task::perform(
+                        find_unoccupied_rooms(ApiClient::new(&global_state), free_rooms),
+                        |res| Self::map_result(res, HomeMessage::FreeRoomsLoaded),
+                    ),
+                ])
+                .chain(guest_tasks),
+        )
+    }
+
+    fn load_free_rooms(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        let Some(ids) = &self.free_room_ids else {
+            return Task::none();
+        };
+        self.free_rooms.retain(|room| ids.contains(&room.id));
+
+        Task::batch(ids.iter().take(TILE_LIST_SIZE).map(|id| {
+            Task::perform(get_room(ApiClient::new(&global_state), *id), |res| {
+                Self::map_result(res, |room| HomeMessage::FreeRoomLoaded(Box::new(room)))
+            })
+        }))
+    }
+
+    fn get_tile_style(theme: &Theme) -> Style {
+        let color = theme.palette().primary;
+
+        Style {
+            border: Border {
+                color,
+                width: 2.0,
+                radius: Radius::new(4),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn view_tile<'a>(
+        title: &'a str,
+        value: String,
+        details: Vec<String>,
+        link: Option<ScreenType>,
+    ) -> Element<'a, AppMessage> {
+        let details = Column::with_children(details.into_iter().map(|detail| text(detail).into()));
+        let open_button =
+            link.map(|screen_type| button("Open").on_press(AppMessage::NavigateTo(screen_type)));
+
+        container(
+            column![text(title), text(value).size(TILE_VALUE_SIZE), details]
+                .push_maybe(open_button)
+                .spacing(5),
+        )
+        .width(TILE_WIDTH)
+        .padding(10)
+        .style(Self::get_tile_style)
+        .into()
+    }
+
+    fn count_to_string(count: Option<usize>) -> String {
+        count.map_or_else(|| "...".to_owned(), |count| count.to_string())
+    }
+
+    fn occupancy_to_string(&self) -> String {
+        match (self.occupied_rooms, &self.free_room_ids) {
+            (Some(occupied), Some(free)) if occupied + free.len() > 0 => {
+                let total = occupied + free.len();
+                format!("{}%", occupied * 100 / total)
+            }
+            (Some(_), Some(_)) => "0%".to_owned(),
+            _ => "...".to_owned(),
+        }
+    }
+
+    fn view_tiles(&self, global_state: Arc<Mutex<GlobalState>>) -> Element<'_, AppMessage> {
+        let role = Self::get_role(&global_state);
+        let link =
+            |screen_type: ScreenType| screen_type.is_allowed_for(role).then_some(screen_type);
+
+        if role != Some(Role::Admin) {
+            return Row::with_children([
+                Self::view_tile(
+                    "My arrivals today",
+                    Self::count_to_string(self.arrivals),
+                    vec![],
+                    link(ScreenType::MyBookings),
+                ),
+                Self::view_tile(
+                    "My departures today",
+                    Self::count_to_string(self.departures),
+                    vec![],
+                    link(ScreenType::MyBookings),
+                ),
+            ])
+            .spacing(FORM_SPACING)
+            .wrap()
+            .into();
+        }
+
+        let mut free_rooms: Vec<_> = self.free_rooms.iter().collect();
+        free_rooms.sort_by(|a, b| a.room_number.cmp(&b.room_number));
+        let free_room_details = free_rooms
+            .iter()
+            .map(|room| format!("Room {} (floor {})", room.room_number, room.floor))
+            .collect();
+
+        let recent_guest_details = self
+            .recent_guest_ids
+            .iter()
+            .filter_map(|id| self.recent_guests.iter().find(|guest| guest.id == *id))
+            .map(|guest| format!("{} {}", guest.first_name, guest.last_name))
+            .collect();
+
+        Row::with_children([
+            Self::view_tile(
+                "Arrivals today",
+                Self::count_to_string(self.arrivals),
+                vec![],
+                link(ScreenType::Today),
+            ),
+            Self::view_tile(
+                "Departures today",
+                Self::count_to_string(self.departures),
+                vec![],
+                link(ScreenType::Today),
+            ),
+            Self::view_tile(
+                "Occupancy tonight",
+                self.occupancy_to_string(),
+                vec![format!(
+                    "{} rooms occupied",
+                    Self::count_to_string(self.occupied_rooms)
+                )],
+                None,
+            ),
+            Self::view_tile(
+                "Free rooms tonight",
+                Self::count_to_string(self.free_room_ids.as_ref().map(Vec::len)),
+                free_room_details,
+                link(ScreenType::BookRoom).or(link(ScreenType::SearchAvailability)),
+            ),
+            Self::view_tile(
+                "Recently added guests",
+                self.recent_guest_ids.len().to_string(),
+                recent_guest_details,
+                link(ScreenType::AddGuest),
+            ),
+        ])
+        .spacing(FORM_SPACING)
+        .wrap()
+        .into()
     }
 }
 impl Screen for HomeScreen {
+    fn on_open(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.refresh(global_state)
+    }
+
     fn update(
         &mut self,
-        _message: AppMessage,
-        _global_state: Arc<Mutex<GlobalState>>,
+        message: AppMessage,
+        global_state: Arc<Mutex<GlobalState>>,
     ) -> Task<AppMessage> {
-        Task::none()
+        match message {
+            AppMessage::HomeMessage(home_message) => match home_message {
+                HomeMessage::Refresh => self.refresh(global_state),
+                HomeMessage::ArrivalsLoaded(ids) => {
+                    self.arrivals = Some(ids.len());
+                    Task::none()
+                }
+                HomeMessage::DeparturesLoaded(ids) => {
+                    self.departures = Some(ids.len());
+                    Task::none()
+                }
+                HomeMessage::OccupiedLoaded(ids) => {
+                    self.occupied_rooms = Some(ids.len());
+                    Task::none()
+                }
+                HomeMessage::FreeRoomsLoaded(ids) => {
+                    self.free_room_ids = Some(ids);
+                    self.load_free_rooms(global_state)
+                }
+                HomeMessage::FreeRoomLoaded(room) => {
+                    self.free_rooms.retain(|loaded| loaded.id != room.id);
+                    self.free_rooms.push(*room);
+                    Task::none()
+                }
+                HomeMessage::RecentGuestLoaded(guest) => {
+                    self.recent_guests.retain(|loaded| loaded.id != guest.id);
+                    self.recent_guests.push(*guest);
+                    Task::none()
+                }
+                HomeMessage::SetError(err) => {
+                    self.error = err;
+                    Task::none()
+                }
+            },
+            _ => Task::none(),
+        }
     }
 
-    fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<AppMessage> {
-        column![text!("Hotel App")
-            .size(TITLE_FONT_SIZE)
-            .width(Fill)
-            .align_x(Center)]
-        .spacing(FORM_SPACING)
-        .padding(FORM_PADDING)
+    fn view(&self, global_state: Arc<Mutex<GlobalState>>) -> Element<AppMessage> {
+        scrollable(
+            column![
+                text!("Dashboard")
+                    .size(TITLE_FONT_SIZE)
+                    .width(Fill)
+                    .align_x(Center),
+                self.view_tiles(global_state),
+                text!("{}", self.error).color(ERROR_COLOR).size(18),
+                button("Refresh").on_press(AppMessage::HomeMessage(HomeMessage::Refresh)),
+            ]
+            .spacing(FORM_SPACING)
+            .padding(FORM_PADDING),
+        )
         .into()
     }
 }
@@ -11,12 +11,16 @@ use iced::{
 use crate::{
     app::{AppMessage, GlobalState, Screen, ScreenType},
     components::{
+        checkbox::Checkbox,
         focus_chain::FocusChain,
         notification::NotificationType,
         text_box::text_box::{TextBox, TextElement},
     },
     constants::{MAX_EMAIL_LENGTH, MAX_PASSWORD_LENGTH},
-    services::{self, send_otp::SendOtpResult},
+    services::{
+        self,
+        api_client::{ApiClient, ApiResult},
+    },
     styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TEXT_BOX_WIDTH},
     utils::show_notification,
 };
@@ -25,6 +29,7 @@ use crate::{
 pub enum LoginMessage {
     ChangeEmail(String),
     ChangePassword(String),
+    ChangeRememberMe(bool),
     ResetPassword,
     OtpSent,
     Login,
@@ -37,6 +42,7 @@ const PASSWORD_ID: &str = "Login Password";
 pub struct LoginScreen {
     email: TextBox,
     password: TextBox,
+    remember_me: Checkbox,
     error: String,
     focus_chain: FocusChain,
 }
@@ -45,11 +51,52 @@ impl LoginScreen {
         Self {
             email: TextBox::new("", MAX_EMAIL_LENGTH),
             password: TextBox::new("", MAX_PASSWORD_LENGTH),
+            remember_me: Checkbox::new("Remember me", false),
             error: "".to_owned(),
             focus_chain: FocusChain::new(vec![EMAIL_ID, PASSWORD_ID]),
         }
     }
 
+    fn validate_input(&self, global_state: &Arc<Mutex<GlobalState>>) -> Result<(), String> {
+        let lock = global_state.lock().unwrap();
+        lock.validator.validate_email(self.email.get_text())?;
+        lock.validator.validate_password(self.password.get_text())
+    }
+
+    fn login(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        if let Err(err) = self.validate_input(&global_state) {
+            self.error = err;
+            return Task::none();
+        }
+
+        let client = ApiClient::new(&global_state);
+        let email = self.email.get_text().to_owned();
+        let password = self.password.get_text().to_owned();
+        let email_copy = email.clone();
+        let remember_session = self.remember_me.is_checked();
+        Task::perform(
+            services::login::login(client, email, password),
+            move |res| match res {
+                ApiResult::Success(token) => {
+                    println!("Set token: '{token:?}'");
+                    let mut lock = global_state.lock().unwrap();
+                    lock.email = Some(email_copy.clone());
+                    lock.remember_session = remember_session;
+                    lock.set_token(token);
+                    AppMessage::NavigateTo(ScreenType::Home)
+                }
+                ApiResult::Forbidden => AppMessage::LoginMessage(LoginMessage::SetError(
+                    "Incorrect credentials".to_owned(),
+                )),
+                ApiResult::BadRequest(err) => AppMessage::LoginMessage(LoginMessage::SetError(err)),
+                ApiResult::Error(err) => {
+                    println!("Error: {err}");
+                    AppMessage::LoginMessage(LoginMessage::SetError("Unexpected error".to_owned()))
+                }
+            },
+        )
+    }
+
     fn reset_password(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
         if global_state
             .lock()
@@ -64,18 +111,20 @@ impl LoginScreen {
 
         let email_input = self.email.get_text().to_owned();
         let email_copy = email_input.clone();
+        let client = ApiClient::new(&global_state);
 
         Task::perform(
-            async { services::send_otp::send_otp(email_input).await },
+            services::send_otp::send_otp(client, email_input),
             move |res| match res {
-                Ok(SendOtpResult::Success) => {
+                ApiResult::Success(_) => {
                     global_state.lock().unwrap().email = Some(email_copy.clone());
                     AppMessage::LoginMessage(LoginMessage::OtpSent)
                 }
-                Ok(SendOtpResult::BadRequest(err)) => {
-                    AppMessage::LoginMessage(LoginMessage::SetError(err))
-                }
-                Err(err) => {
+                ApiResult::BadRequest(err) => AppMessage::LoginMessage(LoginMessage::SetError(err)),
+                ApiResult::Forbidden => AppMessage::LoginMessage(LoginMessage::SetError(
+                    "Password reset not allowed".to_owned(),
+                )),
+                ApiResult::Error(err) => {
                     println!("Error sending otp: {err}");
                     AppMessage::LoginMessage(LoginMessage::SetError(err))
                 }
@@ -101,26 +150,11 @@ impl Screen for LoginScreen {
                     self.password.update(password);
                     Task::none()
                 }
-                LoginMessage::Login => {
-                    let global_state_input = global_state.clone();
-                    let global_state_copy = global_state.clone();
-                    let email = self.email.get_text().to_owned();
-                    let password = self.password.get_text().to_owned();
-                    Task::perform(
-                        services::login::login(global_state_input, email, password),
-                        move |res| match res {
-                            Ok(token) => {
-                                println!("Set token: '{token:?}'");
-                                global_state_copy.lock().unwrap().token = Some(token);
-                                AppMessage::NavigateTo(ScreenType::Home)
-                            }
-                            Err(err) => {
-                                println!("Error: {err}");
-                                AppMessage::LoginMessage(LoginMessage::SetError(err))
-                            }
-                        },
-                    )
+                LoginMessage::ChangeRememberMe(remember_me) => {
+                    self.remember_me.update(remember_me);
+                    Task::none()
                 }
+                LoginMessage::Login => self.login(global_state),
                 LoginMessage::ResetPassword => self.reset_password(global_state),
                 LoginMessage::OtpSent => Task::done(show_notification(
                     "Code sent, check your email",
@@ -146,7 +180,13 @@ impl Screen for LoginScreen {
         }
     }
 
-    fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<crate::app::AppMessage> {
+    fn view(&self, global_state: Arc<Mutex<GlobalState>>) -> Element<crate::app::AppMessage> {
+        let environment = global_state
+            .lock()
+            .unwrap()
+            .config
+            .environment_description();
+
         column![
             text!("Login")
                 .height(40)
@@ -168,6 +208,8 @@ impl Screen for LoginScreen {
                 .secure(true)
                 .width(TEXT_BOX_WIDTH)
                 .line_height(1.5),
+            self.remember_me
+                .view(|x| AppMessage::LoginMessage(LoginMessage::ChangeRememberMe(x))),
             text!("{}", self.error)
                 .color(ERROR_COLOR)
                 .size(18)
@@ -187,7 +229,11 @@ impl Screen for LoginScreen {
                     .height(30)
                     .width(140),
             ]
-            .spacing(10)
+            .spacing(10),
+            text!("Connected to: {environment}")
+                .size(14)
+                .align_x(Center)
+                .width(Fill)
         ]
         .spacing(FORM_SPACING)
         .padding(FORM_PADDING)
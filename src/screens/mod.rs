@@ -1,7 +1,17 @@
 pub mod add_guest;
 pub mod add_room;
 pub mod book_room;
+pub mod booking_details;
+pub mod bookings;
+pub mod expiring_documents;
+pub mod guests;
 pub mod home;
 pub mod login;
+pub mod my_account;
+pub mod my_bookings;
+pub mod occupancy_calendar;
 pub mod register;
 pub mod reset_password;
+pub mod rooms;
+pub mod search_availability;
+pub mod today;
@@ -0,0 +1,145 @@
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Local};
+use iced::{
+    widget::{button, column, row, text},
+    Alignment::Center,
+    Element,
+    Length::Fill,
+    Task,
+};
+
+use crate::{
+    app::{AppMessage, GlobalState, Screen, ScreenType},
+    components::notification::NotificationType,
+    services::{
+        api_client::{ApiClient, ApiResult},
+        send_otp::send_otp,
+    },
+    styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE},
+    utils::show_notification,
+};
+
+#[derive(Debug, Clone)]
+pub enum MyAccountMessage {
+    ChangePassword,
+    OtpSent,
+    SetError(String),
+}
+
+pub struct MyAccountScreen {
+    error: String,
+}
+impl MyAccountScreen {
+    pub fn new() -> Self {
+        Self {
+            error: "".to_owned(),
+        }
+    }
+
+    fn change_password(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        let Some(email) = global_state.lock().unwrap().email.clone() else {
+            self.error = "No email known for this session, log in again".to_owned();
+            return Task::none();
+        };
+
+        Task::perform(
+            send_otp(ApiClient::new(&global_state), email),
+            |res| match res {
+                ApiResult::Success(_) => AppMessage::MyAccountMessage(MyAccountMessage::OtpSent),
+                ApiResult::BadRequest(err) => {
+                    AppMessage::MyAccountMessage(MyAccountMessage::SetError(err))
+                }
+                ApiResult::Forbidden => AppMessage::MyAccountMessage(MyAccountMessage::SetError(
+                    "Password change not allowed".to_owned(),
+                )),
+                ApiResult::Error(err) => {
+                    println!("Error sending otp: {err}");
+                    show_notification("Unexpected error", NotificationType::Error)
+                }
+            },
+        )
+    }
+
+    fn timestamp_to_string(timestamp: u64) -> String {
+        i64::try_from(timestamp)
+            .ok()
+            .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+            .map_or_else(
+                || "Unknown".to_owned(),
+                |date_time| {
+                    date_time
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string()
+                },
+            )
+    }
+
+    fn view_field<'a>(label: &'a str, value: String) -> Element<'a, AppMessage> {
+        row![text(label).width(150), text(value)].spacing(10).into()
+    }
+}
+impl Screen for MyAccountScreen {
+    fn update(
+        &mut self,
+        message: AppMessage,
+        global_state: Arc<Mutex<GlobalState>>,
+    ) -> Task<AppMessage> {
+        match message {
+            AppMessage::MyAccountMessage(m) => match m {
+                MyAccountMessage::ChangePassword => self.change_password(global_state),
+                MyAccountMessage::OtpSent => Task::done(show_notification(
+                    "Code sent, check your email",
+                    NotificationType::Information,
+                ))
+                .chain(Task::done(AppMessage::NavigateTo(
+                    ScreenType::ResetPassword,
+                ))),
+                MyAccountMessage::SetError(err) => {
+                    self.error = err;
+                    Task::none()
+                }
+            },
+            _ => Task::none(),
+        }
+    }
+
+    fn view(&self, global_state: Arc<Mutex<GlobalState>>) -> Element<'_, AppMessage> {
+        let lock = global_state.lock().unwrap();
+        let email = lock.email.clone().unwrap_or_default();
+        let (role, expires) = lock.token.as_ref().map_or_else(
+            || ("".to_owned(), "".to_owned()),
+            |token| {
+                (
+                    format!("{:?}", token.role),
+                    Self::timestamp_to_string(token.exp),
+                )
+            },
+        );
+        let environment = lock.config.environment_description();
+        drop(lock);
+
+        column![
+            text!("My account")
+                .size(TITLE_FONT_SIZE)
+                .width(Fill)
+                .align_x(Center),
+            Self::view_field("Email", email),
+            Self::view_field("Role", role),
+            Self::view_field("Session expires", expires),
+            Self::view_field("Environment", environment),
+            text!("{}", self.error).color(ERROR_COLOR).size(18),
+            row![
+                button("Change password").on_press(AppMessage::MyAccountMessage(
+                    MyAccountMessage::ChangePassword
+                )),
+                button("Logout").on_press(AppMessage::Logout),
+            ]
+            .spacing(10),
+        ]
+        .spacing(FORM_SPACING)
+        .padding(FORM_PADDING)
+        .into()
+    }
+}
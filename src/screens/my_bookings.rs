@@ -0,0 +1,258 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use iced::{
+    border::Radius,
+    widget::{button, column, container, container::Style, scrollable, text, Column},
+    Alignment::Center,
+    Border, Element,
+    Length::Fill,
+    Task, Theme,
+};
+use uuid::Uuid;
+
+use crate::{
+    app::{AppMessage, GlobalState, Screen, ScreenType},
+    model::{booking::Booking, guest::Guest, room::Room},
+    services::{
+        api_client::{ApiClient, ApiResult},
+        find_bookings::{find_bookings, FindBookingsInput},
+        get_booking::get_booking,
+        get_guest::get_guest,
+        get_room::get_room,
+    },
+    styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE},
+};
+
+#[derive(Debug, Clone)]
+pub enum MyBookingsMessage {
+    Refresh,
+    BookingsFound(Vec<Uuid>),
+    BookingLoaded(Box<Booking>),
+    RoomLoaded(Box<Room>),
+    GuestLoaded(Box<Guest>),
+    SetError(String),
+}
+
+const BOOKING_WIDTH: u16 = 450;
+
+pub struct MyBookingsScreen {
+    booking_ids: Option<Vec<Uuid>>,
+    bookings: HashMap<Uuid, Booking>,
+    rooms: HashMap<Uuid, Room>,
+    guests: HashMap<Uuid, Guest>,
+    error: String,
+}
+impl MyBookingsScreen {
+    pub fn new() -> Self {
+        Self {
+            booking_ids: None,
+            bookings: HashMap::new(),
+            rooms: HashMap::new(),
+            guests: HashMap::new(),
+            error: "".to_owned(),
+        }
+    }
+
+    fn map_result<T, F>(result: ApiResult<T>, on_success: F) -> AppMessage
+    where
+        F: FnOnce(T) -> MyBookingsMessage,
+    {
+        match result {
+            ApiResult::Success(ok) => AppMessage::MyBookingsMessage(on_success(ok)),
+            ApiResult::Forbidden => AppMessage::TokenExpired,
+            ApiResult::BadRequest(err) => {
+                AppMessage::MyBookingsMessage(MyBookingsMessage::SetError(err))
+            }
+            ApiResult::Error(err) => {
+                println!("Error loading bookings: {err}");
+                AppMessage::MyBookingsMessage(MyBookingsMessage::SetError(
+                    "Unexpected error".to_owned(),
+                ))
+            }
+        }
+    }
+
+    fn refresh(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.error = "".to_owned();
+        let input = FindBookingsInput {
+            mine: true,
+            ..Default::default()
+        };
+
+        Task::perform(find_bookings(ApiClient::new(&global_state), input), |res| {
+            Self::map_result(res, MyBookingsMessage::BookingsFound)
+        })
+    }
+
+    fn load_bookings(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        ids: Vec<Uuid>,
+    ) -> Task<AppMessage> {
+        self.bookings.retain(|id, _| ids.contains(id));
+        let tasks: Vec<_> = ids
+            .iter()
+            .map(|id| {
+                Task::perform(get_booking(ApiClient::new(&global_state), *id), |res| {
+                    Self::map_result(res, |booking| {
+                        MyBookingsMessage::BookingLoaded(Box::new(booking))
+                    })
+                })
+            })
+            .collect();
+        self.booking_ids = Some(ids);
+
+        Task::batch(tasks)
+    }
+
+    fn load_details(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        booking: Booking,
+    ) -> Task<AppMessage> {
+        let mut tasks = vec![];
+        if !self.rooms.contains_key(&booking.room_id) {
+            tasks.push(Task::perform(
+                get_room(ApiClient::new(&global_state), booking.room_id),
+                |res| Self::map_result(res, |room| MyBookingsMessage::RoomLoaded(Box::new(room))),
+            ));
+        }
+        if !self.guests.contains_key(&booking.main_guest) {
+            tasks.push(Task::perform(
+                get_guest(ApiClient::new(&global_state), booking.main_guest),
+                |res| {
+                    Self::map_result(res, |guest| MyBookingsMessage::GuestLoaded(Box::new(guest)))
+                },
+            ));
+        }
+        self.bookings.insert(booking.id, booking);
+
+        Task::batch(tasks)
+    }
+
+    fn get_booking_style(theme: &Theme) -> Style {
+        let color = theme.palette().primary;
+
+        Style {
+            border: Border {
+                color,
+                width: 2.0,
+                radius: Radius::new(4),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn view_booking(&self, booking: &Booking) -> Element<'_, AppMessage> {
+        let room_text = self.rooms.get(&booking.room_id).map_or_else(
+            || "Loading room ...".to_owned(),
+            |room| format!("Room {} (floor {})", room.room_number, room.floor),
+        );
+        let guest_text = self.guests.get(&booking.main_guest).map_or_else(
+            || "Loading guest ...".to_owned(),
+            |guest| format!("{} {}", guest.first_name, guest.last_name),
+        );
+
+        container(
+            column![
+                text(room_text),
+                text!("From {} to {}", booking.start_date, booking.end_date),
+                text!(
+                    "Main guest: {guest_text}, {} other guests",
+                    booking.other_guests.len()
+                ),
+            ]
+            .spacing(5),
+        )
+        .width(BOOKING_WIDTH)
+        .padding(10)
+        .style(Self::get_booking_style)
+        .into()
+    }
+
+    fn view_bookings(&self) -> Element<'_, AppMessage> {
+        let Some(ids) = &self.booking_ids else {
+            return text("Loading ...").into();
+        };
+        if ids.is_empty() {
+            return column![
+                text("No bookings found"),
+                button("Search availability")
+                    .on_press(AppMessage::NavigateTo(ScreenType::SearchAvailability)),
+            ]
+            .spacing(10)
+            .align_x(Center)
+            .into();
+        }
+
+        let mut bookings: Vec<_> = ids.iter().filter_map(|id| self.bookings.get(id)).collect();
+        bookings.sort_by_key(|booking| {
+            let date = booking.start_date;
+            std::cmp::Reverse((date.year, date.month, date.day))
+        });
+
+        Column::with_children(
+            bookings
+                .into_iter()
+                .map(|booking| self.view_booking(booking)),
+        )
+        .spacing(5)
+        .into()
+    }
+}
+impl Screen for MyBookingsScreen {
+    fn on_open(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.refresh(global_state)
+    }
+
+    fn update(
+        &mut self,
+        message: AppMessage,
+        global_state: Arc<Mutex<GlobalState>>,
+    ) -> Task<AppMessage> {
+        match message {
+            AppMessage::MyBookingsMessage(my_bookings_message) => match my_bookings_message {
+                MyBookingsMessage::Refresh => self.refresh(global_state),
+                MyBookingsMessage::BookingsFound(ids) => self.load_bookings(global_state, ids),
+                MyBookingsMessage::BookingLoaded(booking) => {
+                    self.load_details(global_state, *booking)
+                }
+                MyBookingsMessage::RoomLoaded(room) => {
+                    self.rooms.insert(room.id, *room);
+                    Task::none()
+                }
+                MyBookingsMessage::GuestLoaded(guest) => {
+                    self.guests.insert(guest.id, *guest);
+                    Task::none()
+                }
+                MyBookingsMessage::SetError(err) => {
+                    self.error = err;
+                    Task::none()
+                }
+            },
+            _ => Task::none(),
+        }
+    }
+
+    fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<'_, AppMessage> {
+        scrollable(
+            column![
+                text!("My bookings")
+                    .size(TITLE_FONT_SIZE)
+                    .width(Fill)
+                    .align_x(Center),
+                self.view_bookings(),
+                text!("{}", self.error).color(ERROR_COLOR).size(18),
+                button("Refresh")
+                    .on_press(AppMessage::MyBookingsMessage(MyBookingsMessage::Refresh)),
+            ]
+            .spacing(FORM_SPACING)
+            .padding(FORM_PADDING)
+            .align_x(Center),
+        )
+        .into()
+    }
+}
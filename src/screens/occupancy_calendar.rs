@@ -0,0 +1,360 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use chrono::{Datelike, NaiveDate};
+use iced::{
+    widget::{
+        button, column, row, scrollable,
+        scrollable::{Direction, Scrollbar},
+        text,
+    },
+    Alignment::Center,
+    Element,
+    Length::Fill,
+    Task,
+};
+use iced_aw::date_picker::Date;
+use uuid::Uuid;
+
+use crate::{
+    app::{AppMessage, GlobalState, Screen, ScreenType},
+    components::{
+        notification::NotificationType,
+        occupancy_grid::{GridBar, GridRow, OccupancyGrid},
+    },
+    model::{booking::Booking, booking_status::BookingStatus, guest::Guest, room::Room},
+    services::{
+        api_client::{ApiClient, ApiResult},
+        find_bookings::{find_bookings, FindBookingsInput},
+        find_rooms::find_rooms,
+        get_booking::get_booking,
+        get_guest::get_guest,
+        get_room::get_room,
+    },
+    styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE},
+    utils::{add_days, show_notification},
+};
+
+#[derive(Debug, Clone)]
+pub enum OccupancyCalendarMessage {
+    PreviousWeek,
+    NextWeek,
+    CurrentWeek,
+    RoomsFound(Vec<Uuid>),
+    RoomLoaded(Box<Room>),
+    RoomLoadFailed,
+    BookingsFound(Vec<Uuid>),
+    BookingLoaded(Box<Booking>),
+    GuestLoaded(Box<Guest>),
+    BookRoom(Uuid, Date),
+    OpenBooking(Uuid),
+    SetError(String),
+}
+
+const DAYS_SHOWN: i64 = 14;
+const DAYS_IN_WEEK: i64 = 7;
+
+pub struct OccupancyCalendarScreen {
+    start_date: Date,
+    room_ids: Option<Vec<Uuid>>,
+    rooms: HashMap<Uuid, Room>,
+    failed_rooms: usize,
+    bookings: HashMap<Uuid, Booking>,
+    guests: HashMap<Uuid, Guest>,
+    error: String,
+}
+impl OccupancyCalendarScreen {
+    pub fn new() -> Self {
+        Self {
+            start_date: Self::get_week_start(Date::today()),
+            room_ids: None,
+            rooms: HashMap::new(),
+            failed_rooms: 0,
+            bookings: HashMap::new(),
+            guests: HashMap::new(),
+            error: "".to_owned(),
+        }
+    }
+
+    fn map_result<T, F>(result: ApiResult<T>, on_success: F) -> AppMessage
+    where
+        F: FnOnce(T) -> OccupancyCalendarMessage,
+    {
+        match result {
+            ApiResult::Success(ok) => AppMessage::OccupancyCalendarMessage(on_success(ok)),
+            ApiResult::Forbidden => AppMessage::TokenExpired,
+            ApiResult::BadRequest(err) => {
+                AppMessage::OccupancyCalendarMessage(OccupancyCalendarMessage::SetError(err))
+            }
+            ApiResult::Error(err) => {
+                println!("Error loading occupancy: {err}");
+                show_notification("Unexpected error", NotificationType::Error)
+            }
+        }
+    }
+
+    fn get_week_start(date: Date) -> Date {
+        let naive_date: NaiveDate = date.into();
+        let days_from_monday = naive_date.weekday().num_days_from_monday() as i64;
+
+        add_days(date, -days_from_monday)
+    }
+
+    fn load_rooms(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        if self.room_ids.is_some() {
+            return Task::none();
+        }
+
+        Task::perform(find_rooms(ApiClient::new(&global_state)), |res| {
+            Self::map_result(res, OccupancyCalendarMessage::RoomsFound)
+        })
+    }
+
+    fn load_bookings(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.error = "".to_owned();
+        self.bookings.clear();
+        let input = FindBookingsInput {
+            from: Some(self.start_date),
+            to: Some(add_days(self.start_date, DAYS_SHOWN)),
+            ..Default::default()
+        };
+
+        Task::perform(find_bookings(ApiClient::new(&global_state), input), |res| {
+            Self::map_result(res, OccupancyCalendarMessage::BookingsFound)
+        })
+    }
+
+    fn change_week(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        start_date: Date,
+    ) -> Task<AppMessage> {
+        self.start_date = start_date;
+        self.load_bookings(global_state)
+    }
+
+    fn load_main_guest(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        booking: Booking,
+    ) -> Task<AppMessage> {
+        let guest_id = booking.main_guest;
+        self.bookings.insert(booking.id, booking);
+        if self.guests.contains_key(&guest_id) {
+            return Task::none();
+        }
+
+        Task::perform(get_guest(ApiClient::new(&global_state), guest_id), |res| {
+            Self::map_result(res, |guest| {
+                OccupancyCalendarMessage::GuestLoaded(Box::new(guest))
+            })
+        })
+    }
+
+    fn get_rows(&self) -> Vec<GridRow<'_>> {
+        let mut rooms: Vec<_> = self.rooms.values().collect();
+        rooms.sort_by(|a, b| (a.floor, &a.room_number).cmp(&(b.floor, &b.room_number)));
+
+        let mut rows = vec![];
+        let mut current_floor = None;
+        for room in rooms {
+            if current_floor != Some(room.floor) {
+                current_floor = Some(room.floor);
+                rows.push(GridRow::Floor(room.floor));
+            }
+            rows.push(GridRow::Room(room));
+        }
+
+        rows
+    }
+
+    fn get_bars(&self) -> Vec<GridBar> {
+        self.bookings
+            .values()
+            .filter(|booking| booking.status != BookingStatus::Cancelled)
+            .map(|booking| GridBar {
+                booking_id: booking.id,
+                room_id: booking.room_id,
+                start_date: booking.start_date,
+                end_date: booking.end_date,
+                status: booking.status,
+                label: self
+                    .guests
+                    .get(&booking.main_guest)
+                    .map(|guest| guest.last_name.clone())
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    fn view_grid(&self) -> Element<'_, AppMessage> {
+        let Some(room_ids) = &self.room_ids else {
+            return text("Loading rooms ...").into();
+        };
+        if room_ids.is_empty() {
+            return text("No rooms found").into();
+        }
+        let done = self.rooms.len() + self.failed_rooms;
+        if done < room_ids.len() {
+            return text!("Loading rooms {done}/{} ...", room_ids.len()).into();
+        }
+
+        let failed = (self.failed_rooms > 0)
+            .then(|| text!("{} rooms could not be loaded", self.failed_rooms).color(ERROR_COLOR));
+        let grid = OccupancyGrid::new(
+            self.get_rows(),
+            self.get_bars(),
+            self.start_date,
+            DAYS_SHOWN,
+            |room_id, date| {
+                AppMessage::OccupancyCalendarMessage(OccupancyCalendarMessage::BookRoom(
+                    room_id, date,
+                ))
+            },
+            |booking_id| {
+                AppMessage::OccupancyCalendarMessage(OccupancyCalendarMessage::OpenBooking(
+                    booking_id,
+                ))
+            },
+        )
+        .view();
+
+        column![grid].push_maybe(failed).spacing(10).into()
+    }
+}
+impl Screen for OccupancyCalendarScreen {
+    fn keep_alive(&self) -> bool {
+        true
+    }
+
+    fn on_open(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        Task::batch([
+            self.load_rooms(global_state.clone()),
+            self.load_bookings(global_state),
+        ])
+    }
+
+    fn update(
+        &mut self,
+        message: AppMessage,
+        global_state: Arc<Mutex<GlobalState>>,
+    ) -> Task<AppMessage> {
+        match message {
+            AppMessage::OccupancyCalendarMessage(m) => match m {
+                OccupancyCalendarMessage::PreviousWeek => {
+                    self.change_week(global_state, add_days(self.start_date, -DAYS_IN_WEEK))
+                }
+                OccupancyCalendarMessage::NextWeek => {
+                    self.change_week(global_state, add_days(self.start_date, DAYS_IN_WEEK))
+                }
+                OccupancyCalendarMessage::CurrentWeek => {
+                    self.change_week(global_state, Self::get_week_start(Date::today()))
+                }
+                OccupancyCalendarMessage::RoomsFound(ids) => {
+                    let tasks: Vec<_> = ids
+                        .iter()
+                        .map(|id| {
+                            Task::perform(get_room(ApiClient::new(&global_state), *id), |res| {
+                                match res {
+                                    ApiResult::Success(room) => {
+                                        AppMessage::OccupancyCalendarMessage(
+                                            OccupancyCalendarMessage::RoomLoaded(Box::new(room)),
+                                        )
+                                    }
+                                    ApiResult::Forbidden => AppMessage::TokenExpired,
+                                    ApiResult::BadRequest(err) | ApiResult::Error(err) => {
+                                        println!("Error loading room: {err}");
+                                        AppMessage::OccupancyCalendarMessage(
+                                            OccupancyCalendarMessage::RoomLoadFailed,
+                                        )
+                                    }
+                                }
+                            })
+                        })
+                        .collect();
+                    self.failed_rooms = 0;
+                    self.room_ids = Some(ids);
+                    Task::batch(tasks)
+                }
+                OccupancyCalendarMessage::RoomLoaded(room) => {
+                    self.rooms.insert(room.id, *room);
+                    Task::none()
+                }
+                OccupancyCalendarMessage::RoomLoadFailed => {
+                    self.failed_rooms += 1;
+                    Task::none()
+                }
+                OccupancyCalendarMessage::BookingsFound(ids) => {
+                    Task::batch(ids.into_iter().map(|id| {
+                        Task::perform(get_booking(ApiClient::new(&global_state), id), |res| {
+                            Self::map_result(res, |booking| {
+                                OccupancyCalendarMessage::BookingLoaded(Box::new(booking))
+                            })
+                        })
+                    }))
+                }
+                OccupancyCalendarMessage::BookingLoaded(booking) => {
+                    self.load_main_guest(global_state, *booking)
+                }
+                OccupancyCalendarMessage::GuestLoaded(guest) => {
+                    self.guests.insert(guest.id, *guest);
+                    Task::none()
+                }
+                OccupancyCalendarMessage::BookRoom(room_id, date) => Task::done(
+                    AppMessage::NavigateTo(ScreenType::BookRoomFor(room_id, date)),
+                ),
+                OccupancyCalendarMessage::OpenBooking(booking_id) => {
+                    match self.bookings.get(&booking_id) {
+                        Some(booking) => Task::done(AppMessage::NavigateTo(
+                            ScreenType::BookingDetails(Box::new(booking.clone())),
+                        )),
+                        None => Task::none(),
+                    }
+                }
+                OccupancyCalendarMessage::SetError(err) => {
+                    self.error = err;
+                    Task::none()
+                }
+            },
+            _ => Task::none(),
+        }
+    }
+
+    fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<'_, AppMessage> {
+        let end_date = add_days(self.start_date, DAYS_SHOWN - 1);
+
+        column![
+            text!("Occupancy")
+                .size(TITLE_FONT_SIZE)
+                .width(Fill)
+                .align_x(Center),
+            row![
+                button("Previous week").on_press(AppMessage::OccupancyCalendarMessage(
+                    OccupancyCalendarMessage::PreviousWeek
+                )),
+                button("This week").on_press(AppMessage::OccupancyCalendarMessage(
+                    OccupancyCalendarMessage::CurrentWeek
+                )),
+                button("Next week").on_press(AppMessage::OccupancyCalendarMessage(
+                    OccupancyCalendarMessage::NextWeek
+                )),
+                text!("{} - {}", self.start_date, end_date),
+            ]
+            .spacing(10)
+            .align_y(Center),
+            text("Click a free cell to book the room from that night").size(14),
+            text!("{}", self.error).color(ERROR_COLOR).size(18),
+            scrollable(self.view_grid())
+                .direction(Direction::Both {
+                    vertical: Scrollbar::default(),
+                    horizontal: Scrollbar::default(),
+                })
+                .height(Fill),
+        ]
+        .spacing(FORM_SPACING)
+        .padding(FORM_PADDING)
+        .into()
+    }
+}
@@ -18,7 +18,8 @@ use crate::{
     constants::{MAX_EMAIL_LENGTH, MAX_PASSWORD_LENGTH},
     services::{
         self,
-        register::{RegisterInput, RegisterResult},
+        api_client::{ApiClient, ApiResult},
+        register::RegisterInput,
     },
     styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TEXT_BOX_WIDTH},
     utils::show_notification,
@@ -68,28 +69,35 @@ impl RegisterScreen {
     }
 
     fn register(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
-        if let Err(err) = self.validate_input(global_state) {
+        if let Err(err) = self.validate_input(global_state.clone()) {
             self.error = err;
             return Task::none();
         }
+        let client = ApiClient::new(&global_state);
 
         let input = RegisterInput {
             email: self.email.get_text().to_owned(),
             password: self.password.get_text().to_owned(),
         };
 
-        Task::perform(services::register::register(input), |res| match res {
-            Ok(RegisterResult::Registered(uid)) => {
-                AppMessage::RegisterMessage(RegisterMessage::Registered(uid))
-            }
-            Ok(RegisterResult::BadRequest(err)) => {
-                AppMessage::RegisterMessage(RegisterMessage::UpdateError(err))
-            }
-            Err(err) => {
-                println!("Error registering: '{err}'");
-                show_notification("Unexpected error", NotificationType::Error)
-            }
-        })
+        Task::perform(
+            services::register::register(client, input),
+            |res| match res {
+                ApiResult::Success(uid) => {
+                    AppMessage::RegisterMessage(RegisterMessage::Registered(uid))
+                }
+                ApiResult::BadRequest(err) => {
+                    AppMessage::RegisterMessage(RegisterMessage::UpdateError(err))
+                }
+                ApiResult::Forbidden => AppMessage::RegisterMessage(RegisterMessage::UpdateError(
+                    "Registration not allowed".to_owned(),
+                )),
+                ApiResult::Error(err) => {
+                    println!("Error registering: '{err}'");
+                    show_notification("Unexpected error", NotificationType::Error)
+                }
+            },
+        )
     }
 }
 impl Screen for RegisterScreen {
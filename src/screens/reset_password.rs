@@ -18,8 +18,8 @@ use crate::{
     constants::MAX_PASSWORD_LENGTH,
     services::{
         self,
-        reset_password::{reset_password, ResetPasswordInput, ResetPasswordResult},
-        send_otp::SendOtpResult,
+        api_client::{ApiClient, ApiResult},
+        reset_password::{reset_password, ResetPasswordInput},
     },
     styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TEXT_BOX_WIDTH},
     utils::show_notification,
@@ -60,6 +60,7 @@ impl ResetPasswordScreen {
     }
 
     fn resend_code(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        let client = ApiClient::new(&global_state);
         let email = if let Some(some) = &global_state.lock().unwrap().email {
             some.to_owned()
         } else {
@@ -70,17 +71,20 @@ impl ResetPasswordScreen {
         let email_copy = email.clone();
 
         Task::perform(
-            async { services::send_otp::send_otp(email).await },
+            services::send_otp::send_otp(client, email),
             move |res| match res {
-                Ok(SendOtpResult::Success) => {
+                ApiResult::Success(_) => {
                     AppMessage::ResetPasswordMessage(ResetPasswordMessage::CodeResent {
                         email: email_copy.clone(),
                     })
                 }
-                Ok(SendOtpResult::BadRequest(err)) => {
+                ApiResult::BadRequest(err) => {
                     AppMessage::ResetPasswordMessage(ResetPasswordMessage::SetError(err))
                 }
-                Err(err) => {
+                ApiResult::Forbidden => AppMessage::ResetPasswordMessage(
+                    ResetPasswordMessage::SetError("Password reset not allowed".to_owned()),
+                ),
+                ApiResult::Error(err) => {
                     println!("Error sending otp: {err}");
                     show_notification("Unexpected error", NotificationType::Error)
                 }
@@ -88,15 +92,21 @@ impl ResetPasswordScreen {
         )
     }
 
-    fn create_reset_password_task(input: ResetPasswordInput) -> Task<AppMessage> {
-        Task::perform(reset_password(input), |res| match res {
-            Ok(ResetPasswordResult::PasswordReset) => {
+    fn create_reset_password_task(
+        client: ApiClient,
+        input: ResetPasswordInput,
+    ) -> Task<AppMessage> {
+        Task::perform(reset_password(client, input), |res| match res {
+            ApiResult::Success(_) => {
                 AppMessage::ResetPasswordMessage(ResetPasswordMessage::ResetPasswordSuccess)
             }
-            Ok(ResetPasswordResult::BadRequest(err)) => {
+            ApiResult::BadRequest(err) => {
                 AppMessage::ResetPasswordMessage(ResetPasswordMessage::SetError(err))
             }
-            Err(err) => {
+            ApiResult::Forbidden => AppMessage::ResetPasswordMessage(
+                ResetPasswordMessage::SetError("Invalid code".to_owned()),
+            ),
+            ApiResult::Error(err) => {
                 println!("Error resetting password'{err}'");
                 show_notification("Unexpected error", NotificationType::Error)
             }
@@ -145,7 +155,9 @@ impl ResetPasswordScreen {
             new_password: password,
         };
 
-        Self::create_reset_password_task(input)
+        let client = ApiClient::new(&global_state);
+
+        Self::create_reset_password_task(client, input)
     }
 }
 impl Screen for ResetPasswordScreen {
@@ -184,11 +196,19 @@ impl Screen for ResetPasswordScreen {
                         NotificationType::Information,
                     ))
                 }
-                ResetPasswordMessage::ResetPasswordSuccess => Task::done(show_notification(
-                    "Password reset successful",
-                    NotificationType::Success,
-                ))
-                .chain(Task::done(AppMessage::NavigateTo(ScreenType::Login))),
+                ResetPasswordMessage::ResetPasswordSuccess => {
+                    // A password changed from a logged in session invalidates that session
+                    let next = if global_state.lock().unwrap().token.is_some() {
+                        AppMessage::Logout
+                    } else {
+                        AppMessage::NavigateTo(ScreenType::Login)
+                    };
+                    Task::done(show_notification(
+                        "Password reset successful",
+                        NotificationType::Success,
+                    ))
+                    .chain(Task::done(next))
+                }
             },
             AppMessage::SelectNext => {
                 self.focus_chain.set_next();
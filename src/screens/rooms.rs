@@ -0,0 +1,397 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    sync::{Arc, Mutex},
+};
+
+use iced::{
+    border::Radius,
+    widget::{
+        button, column, container, container::Style, pick_list, row, scrollable, text, text_input,
+        Column,
+    },
+    Alignment::Center,
+    Border, Element,
+    Length::Fill,
+    Task, Theme,
+};
+use uuid::Uuid;
+
+use crate::{
+    app::{AppMessage, GlobalState, Screen, ScreenType},
+    components::{
+        focus_chain::FocusChain,
+        notification::NotificationType,
+        text_box::{
+            number_text_box::{NumberTextBox, NumberType},
+            text_box::{TextBox, TextElement},
+        },
+    },
+    model::{
+        bathroom_type::{BathroomFilter, BATHROOM_FILTERS},
+        room::Room,
+    },
+    services::{
+        api_client::{ApiClient, ApiResult},
+        delete_room::delete_room,
+        find_rooms::find_rooms,
+        get_room::get_room,
+    },
+    styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE},
+    utils::show_notification,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoomSortKey {
+    RoomNumber,
+    Floor,
+    Price,
+}
+impl Display for RoomSortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoomSortKey::RoomNumber => write!(f, "Room number"),
+            RoomSortKey::Floor => write!(f, "Floor"),
+            RoomSortKey::Price => write!(f, "Price"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum RoomsMessage {
+    Refresh,
+    RoomsFound(Vec<Uuid>),
+    RoomLoaded(Box<Room>),
+    ChangeRoomNumberFilter(String),
+    ChangeFloorFilter(String),
+    ChangeBathroomFilter(BathroomFilter),
+    ChangeSortKey(RoomSortKey),
+    EditRoom(Uuid),
+    RequestDelete(Uuid),
+    CancelDelete,
+    ConfirmDelete,
+    RoomDeleted(Uuid),
+    SetError(String),
+}
+
+const ROOM_NUMBER_FILTER_ID: &str = "Rooms Room Number Filter";
+const FLOOR_FILTER_ID: &str = "Rooms Floor Filter";
+
+const SORT_KEYS: [RoomSortKey; 3] = [
+    RoomSortKey::RoomNumber,
+    RoomSortKey::Floor,
+    RoomSortKey::Price,
+];
+const ROOM_WIDTH: u16 = 520;
+
+pub struct RoomsScreen {
+    room_ids: Option<Vec<Uuid>>,
+    rooms: HashMap<Uuid, Room>,
+    room_number_filter: TextBox,
+    floor_filter: NumberTextBox,
+    bathroom_filter: BathroomFilter,
+    sort_key: RoomSortKey,
+    pending_delete: Option<Uuid>,
+    focus_chain: FocusChain,
+    error: String,
+}
+impl RoomsScreen {
+    pub fn new() -> Self {
+        Self {
+            room_ids: None,
+            rooms: HashMap::new(),
+            room_number_filter: TextBox::new("", 10),
+            floor_filter: NumberTextBox::new("", 6, NumberType::PositiveInteger),
+            bathroom_filter: BathroomFilter::Any,
+            sort_key: RoomSortKey::RoomNumber,
+            pending_delete: None,
+            focus_chain: FocusChain::new(vec![ROOM_NUMBER_FILTER_ID, FLOOR_FILTER_ID]),
+            error: "".to_owned(),
+        }
+    }
+
+    fn map_result<T, F>(result: ApiResult<T>, on_success: F) -> AppMessage
+    where
+        F: FnOnce(T) -> RoomsMessage,
+    {
+        match result {
+            ApiResult::Success(ok) => AppMessage::RoomsMessage(on_success(ok)),
+            ApiResult::Forbidden => AppMessage::TokenExpired,
+            ApiResult::BadRequest(err) => AppMessage::RoomsMessage(RoomsMessage::SetError(err)),
+            ApiResult::Error(err) => {
+                println!("Error loading rooms: {err}");
+                show_notification("Unexpected error", NotificationType::Error)
+            }
+        }
+    }
+
+    fn refresh(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.error = "".to_owned();
+        self.pending_delete = None;
+
+        Task::perform(find_rooms(ApiClient::new(&global_state)), |res| {
+            Self::map_result(res, RoomsMessage::RoomsFound)
+        })
+    }
+
+    fn load_rooms(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        ids: Vec<Uuid>,
+    ) -> Task<AppMessage> {
+        self.rooms.clear();
+        let tasks: Vec<_> = ids
+            .iter()
+            .map(|id| {
+                Task::perform(get_room(ApiClient::new(&global_state), *id), |res| {
+                    Self::map_result(res, |room| RoomsMessage::RoomLoaded(Box::new(room)))
+                })
+            })
+            .collect();
+        self.room_ids = Some(ids);
+
+        Task::batch(tasks)
+    }
+
+    fn delete(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        let Some(room_id) = self.pending_delete.take() else {
+            return Task::none();
+        };
+
+        Task::perform(
+            delete_room(ApiClient::new(&global_state), room_id),
+            move |res| Self::map_result(res, |_| RoomsMessage::RoomDeleted(room_id)),
+        )
+    }
+
+    fn matches_filters(&self, room: &Room) -> bool {
+        let room_number_filter = self.room_number_filter.get_text().trim().to_lowercase();
+        if !room
+            .room_number
+            .to_lowercase()
+            .contains(&room_number_filter)
+        {
+            return false;
+        }
+        if let Ok(floor) = self.floor_filter.get_text().parse::<i16>() {
+            if room.floor != floor {
+                return false;
+            }
+        }
+
+        match self.bathroom_filter {
+            BathroomFilter::Any => true,
+            BathroomFilter::Only(bathroom_type) => room.bathroom_type == bathroom_type,
+        }
+    }
+
+    fn get_filtered_rooms(&self) -> Vec<&Room> {
+        let mut rooms: Vec<_> = self
+            .rooms
+            .values()
+            .filter(|room| self.matches_filters(room))
+            .collect();
+        match self.sort_key {
+            RoomSortKey::RoomNumber => rooms.sort_by(|a, b| a.room_number.cmp(&b.room_number)),
+            RoomSortKey::Floor => {
+                rooms.sort_by(|a, b| (a.floor, &a.room_number).cmp(&(b.floor, &b.room_number)))
+            }
+            RoomSortKey::Price => {
+                rooms.sort_by(|a, b| (a.price, &a.room_number).cmp(&(b.price, &b.room_number)))
+            }
+        }
+
+        rooms
+    }
+
+    fn get_room_style(theme: &Theme) -> Style {
+        let color = theme.palette().primary;
+
+        Style {
+            border: Border {
+                color,
+                width: 2.0,
+                radius: Radius::new(4),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn view_room(&self, room: &Room) -> Element<'_, AppMessage> {
+        let beds = room
+            .beds
+            .iter()
+            .map(|bed| format!("{} x {}", bed.count, bed.bed_size.to_string()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let actions = if self.pending_delete == Some(room.id) {
+            row![
+                text!("Delete room {}?", room.room_number),
+                button("Confirm").on_press(AppMessage::RoomsMessage(RoomsMessage::ConfirmDelete)),
+                button("Cancel").on_press(AppMessage::RoomsMessage(RoomsMessage::CancelDelete)),
+            ]
+        } else {
+            row![
+                button("Edit").on_press(AppMessage::RoomsMessage(RoomsMessage::EditRoom(room.id))),
+                button("Delete").on_press(AppMessage::RoomsMessage(RoomsMessage::RequestDelete(
+                    room.id
+                ))),
+            ]
+        };
+
+        container(
+            column![
+                text!("Room {} (floor {})", room.room_number, room.floor),
+                text!(
+                    "Price {:.2}, {} bathroom",
+                    room.price as f64 / 100.0,
+                    room.bathroom_type.to_string()
+                ),
+                text!("Beds: {beds}"),
+                actions.spacing(10).align_y(Center),
+            ]
+            .spacing(5),
+        )
+        .width(ROOM_WIDTH)
+        .padding(10)
+        .style(Self::get_room_style)
+        .into()
+    }
+
+    fn view_filters(&self) -> Element<'_, AppMessage> {
+        row![
+            text_input("Room number", self.room_number_filter.get_text())
+                .id(ROOM_NUMBER_FILTER_ID)
+                .on_input(|x| AppMessage::RoomsMessage(RoomsMessage::ChangeRoomNumberFilter(x)))
+                .width(130)
+                .line_height(1.5),
+            text_input("Floor", self.floor_filter.get_text())
+                .id(FLOOR_FILTER_ID)
+                .on_input(|x| AppMessage::RoomsMessage(RoomsMessage::ChangeFloorFilter(x)))
+                .width(80)
+                .line_height(1.5),
+            pick_list(BATHROOM_FILTERS, Some(self.bathroom_filter), |x| {
+                AppMessage::RoomsMessage(RoomsMessage::ChangeBathroomFilter(x))
+            }),
+            pick_list(SORT_KEYS, Some(self.sort_key), |x| {
+                AppMessage::RoomsMessage(RoomsMessage::ChangeSortKey(x))
+            }),
+        ]
+        .spacing(10)
+        .align_y(Center)
+        .into()
+    }
+
+    fn view_rooms(&self) -> Element<'_, AppMessage> {
+        let Some(ids) = &self.room_ids else {
+            return text("Loading ...").into();
+        };
+        let rooms = self.get_filtered_rooms();
+        if rooms.is_empty() && self.rooms.len() == ids.len() {
+            return text("No rooms found").into();
+        }
+
+        Column::with_children(rooms.into_iter().map(|room| self.view_room(room)))
+            .spacing(5)
+            .into()
+    }
+}
+impl Screen for RoomsScreen {
+    fn on_open(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.refresh(global_state)
+    }
+
+    fn update(
+        &mut self,
+        message: AppMessage,
+        global_state: Arc<Mutex<GlobalState>>,
+    ) -> Task<AppMessage> {
+        match message {
+            AppMessage::RoomsMessage(rooms_message) => match rooms_message {
+                RoomsMessage::Refresh => self.refresh(global_state),
+                RoomsMessage::RoomsFound(ids) => self.load_rooms(global_state, ids),
+                RoomsMessage::RoomLoaded(room) => {
+                    self.rooms.insert(room.id, *room);
+                    Task::none()
+                }
+                RoomsMessage::ChangeRoomNumberFilter(room_number) => {
+                    self.focus_chain.set_focus(Some(ROOM_NUMBER_FILTER_ID));
+                    self.room_number_filter.update(room_number);
+                    Task::none()
+                }
+                RoomsMessage::ChangeFloorFilter(floor) => {
+                    self.focus_chain.set_focus(Some(FLOOR_FILTER_ID));
+                    self.floor_filter.update(floor);
+                    Task::none()
+                }
+                RoomsMessage::ChangeBathroomFilter(bathroom_filter) => {
+                    self.bathroom_filter = bathroom_filter;
+                    Task::none()
+                }
+                RoomsMessage::ChangeSortKey(sort_key) => {
+                    self.sort_key = sort_key;
+                    Task::none()
+                }
+                RoomsMessage::EditRoom(room_id) => match self.rooms.get(&room_id) {
+                    Some(room) => Task::done(AppMessage::NavigateTo(ScreenType::EditRoom(
+                        Box::new(room.clone()),
+                    ))),
+                    None => Task::none(),
+                },
+                RoomsMessage::RequestDelete(room_id) => {
+                    self.pending_delete = Some(room_id);
+                    Task::none()
+                }
+                RoomsMessage::CancelDelete => {
+                    self.pending_delete = None;
+                    Task::none()
+                }
+                RoomsMessage::ConfirmDelete => self.delete(global_state),
+                RoomsMessage::RoomDeleted(room_id) => {
+                    self.rooms.remove(&room_id);
+                    if let Some(ids) = &mut self.room_ids {
+                        ids.retain(|id| *id != room_id);
+                    }
+                    Task::done(show_notification("Room deleted", NotificationType::Success))
+                }
+                RoomsMessage::SetError(err) => {
+                    self.error = err;
+                    Task::none()
+                }
+            },
+            AppMessage::SelectNext => {
+                self.focus_chain.set_next();
+                self.focus_chain.apply_focus()
+            }
+            AppMessage::SelectPrev => {
+                self.focus_chain.set_prev();
+                self.focus_chain.apply_focus()
+            }
+            _ => Task::none(),
+        }
+    }
+
+    fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<'_, AppMessage> {
+        scrollable(
+            column![
+                text!("Rooms")
+                    .size(TITLE_FONT_SIZE)
+                    .width(Fill)
+                    .align_x(Center),
+                self.view_filters(),
+                text!("{}", self.error).color(ERROR_COLOR).size(18),
+                self.view_rooms(),
+                row![
+                    button("Refresh").on_press(AppMessage::RoomsMessage(RoomsMessage::Refresh)),
+                    button("Add Room").on_press(AppMessage::NavigateTo(ScreenType::AddRoom)),
+                ]
+                .spacing(10),
+            ]
+            .spacing(FORM_SPACING)
+            .padding(FORM_PADDING)
+            .align_x(Center),
+        )
+        .into()
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::{Arc, Mutex};
+
+use iced::{
+    widget::{column, container, scrollable, text, Column},
+    Alignment::Center,
+    Element,
+    Length::Fill,
+    Task,
+};
+
+use crate::{
+    app::{AppMessage, GlobalState, Screen},
+    components::{
+        focus_chain::FocusChain,
+        room_search::{RoomSearch, RoomSearchMessage, ROOM_SEARCH_IDS},
+    },
+    model::room::Room,
+    styles::{FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE},
+};
+
+#[derive(Debug, Clone)]
+pub enum SearchAvailabilityMessage {
+    RoomSearch(RoomSearchMessage),
+}
+
+pub struct SearchAvailabilityScreen {
+    room_search: RoomSearch,
+    focus_chain: FocusChain,
+}
+impl SearchAvailabilityScreen {
+    pub fn new() -> Self {
+        Self {
+            room_search: RoomSearch::new(|x| {
+                AppMessage::SearchAvailabilityMessage(SearchAvailabilityMessage::RoomSearch(x))
+            }),
+            focus_chain: FocusChain::new(ROOM_SEARCH_IDS.into()),
+        }
+    }
+
+    fn view_room_details(room: &Room) -> Element<'_, AppMessage> {
+        let beds = Column::with_children(
+            room.beds
+                .iter()
+                .map(|bed| text!("{} x {}", bed.count, bed.bed_size.to_string()).into()),
+        );
+
+        container(
+            column![
+                text!("Room {}, floor {}", room.room_number, room.floor),
+                text!("Price: {:.2}", room.price as f64 / 100.0),
+                text!("Bathroom: {}", room.bathroom_type.to_string()),
+                text!("Beds:"),
+                beds,
+            ]
+            .spacing(5),
+        )
+        .width(450)
+        .into()
+    }
+}
+impl Screen for SearchAvailabilityScreen {
+    fn keep_alive(&self) -> bool {
+        true
+    }
+
+    fn update(
+        &mut self,
+        message: AppMessage,
+        global_state: Arc<Mutex<GlobalState>>,
+    ) -> Task<AppMessage> {
+        match message {
+            AppMessage::SearchAvailabilityMessage(m) => match m {
+                SearchAvailabilityMessage::RoomSearch(room_search_message) => self
+                    .room_search
+                    .update(room_search_message, global_state, &mut self.focus_chain),
+            },
+            AppMessage::SelectNext => {
+                self.focus_chain.set_next();
+                self.focus_chain.apply_focus()
+            }
+            AppMessage::SelectPrev => {
+                self.focus_chain.set_prev();
+                self.focus_chain.apply_focus()
+            }
+            _ => Task::none(),
+        }
+    }
+
+    fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<'_, AppMessage> {
+        let details = self
+            .room_search
+            .selected_room()
+            .map(Self::view_room_details);
+
+        scrollable(
+            column![
+                text!("Search availability")
+                    .align_x(Center)
+                    .size(TITLE_FONT_SIZE)
+                    .width(Fill),
+                self.room_search.view(),
+            ]
+            .push_maybe(details)
+            .spacing(FORM_SPACING)
+            .align_x(Center)
+            .padding(FORM_PADDING),
+        )
+        .into()
+    }
+}
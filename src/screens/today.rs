@@ -0,0 +1,453 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+
+use iced::{
+    widget::{button, column, row, scrollable, text, Column},
+    Alignment::Center,
+    Element,
+    Length::Fill,
+    Task,
+};
+use iced_aw::date_picker::Date;
+use uuid::Uuid;
+
+use crate::{
+    app::{AppMessage, GlobalState, Screen, ScreenType},
+    components::notification::NotificationType,
+    model::{booking::Booking, booking_status::BookingStatus, guest::Guest, room::Room},
+    services::{
+        api_client::{ApiClient, ApiResult},
+        check_in_booking::check_in_booking,
+        check_out_booking::check_out_booking,
+        find_bookings::{find_bookings, FindBookingsInput},
+        get_booking::get_booking,
+        get_guest::get_guest,
+        get_room::get_room,
+        request_room_cleaning::request_room_cleaning,
+    },
+    styles::{ERROR_COLOR, FORM_PADDING, FORM_SPACING, TITLE_FONT_SIZE, WARNING_COLOR},
+    utils::show_notification,
+};
+
+#[derive(Debug, Clone)]
+pub enum TodayMessage {
+    Refresh,
+    ArrivalsFound(Vec<Uuid>),
+    DeparturesFound(Vec<Uuid>),
+    BookingLoaded(Box<Booking>),
+    RoomLoaded(Box<Room>),
+    GuestLoaded(Box<Guest>),
+    GuestLoadFailed(Uuid),
+    CheckIn(Uuid),
+    CheckedIn(Uuid),
+    StartCheckOut(Uuid),
+    CancelCheckOut,
+    ConfirmCheckOut(Uuid),
+    CheckedOut(Uuid),
+    RoomCleaningRequested,
+    OpenBooking(Uuid),
+    SetError(String),
+}
+
+pub struct TodayScreen {
+    arrival_ids: Option<Vec<Uuid>>,
+    departure_ids: Option<Vec<Uuid>>,
+    bookings: HashMap<Uuid, Booking>,
+    rooms: HashMap<Uuid, Room>,
+    guests: HashMap<Uuid, Guest>,
+    failed_guests: HashSet<Uuid>,
+    pending_check_out: Option<Uuid>,
+    error: String,
+}
+impl TodayScreen {
+    pub fn new() -> Self {
+        Self {
+            arrival_ids: None,
+            departure_ids: None,
+            bookings: HashMap::new(),
+            rooms: HashMap::new(),
+            guests: HashMap::new(),
+            failed_guests: HashSet::new(),
+            pending_check_out: None,
+            error: "".to_owned(),
+        }
+    }
+
+    fn map_result<T, F>(result: ApiResult<T>, on_success: F) -> AppMessage
+    where
+        F: FnOnce(T) -> TodayMessage,
+    {
+        match result {
+            ApiResult::Success(ok) => AppMessage::TodayMessage(on_success(ok)),
+            ApiResult::Forbidden => AppMessage::TokenExpired,
+            ApiResult::BadRequest(err) => AppMessage::TodayMessage(TodayMessage::SetError(err)),
+            ApiResult::Error(err) => {
+                println!("Error loading today's bookings: {err}");
+                show_notification("Unexpected error", NotificationType::Error)
+            }
+        }
+    }
+
+    fn refresh(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        let today = Date::today();
+        self.error = "".to_owned();
+        self.pending_check_out = None;
+        self.arrival_ids = None;
+        self.departure_ids = None;
+        // Guests may have updated their documents since the last refresh
+        self.guests.clear();
+        self.failed_guests.clear();
+
+        let arrivals = FindBookingsInput {
+            starts_on: Some(today),
+            ..Default::default()
+        };
+        let departures = FindBookingsInput {
+            ends_on: Some(today),
+            ..Default::default()
+        };
+
+        Task::batch([
+            Task::perform(
+                find_bookings(ApiClient::new(&global_state), arrivals),
+                |res| Self::map_result(res, TodayMessage::ArrivalsFound),
+            ),
+            Task::perform(
+                find_bookings(ApiClient::new(&global_state), departures),
+                |res| Self::map_result(res, TodayMessage::DeparturesFound),
+            ),
+        ])
+    }
+
+    fn load_bookings(global_state: &Arc<Mutex<GlobalState>>, ids: &[Uuid]) -> Task<AppMessage> {
+        Task::batch(ids.iter().map(|id| Self::load_booking(global_state, *id)))
+    }
+
+    fn load_booking(global_state: &Arc<Mutex<GlobalState>>, id: Uuid) -> Task<AppMessage> {
+        Task::perform(get_booking(ApiClient::new(global_state), id), |res| {
+            Self::map_result(res, |booking| {
+                TodayMessage::BookingLoaded(Box::new(booking))
+            })
+        })
+    }
+
+    fn load_booking_details(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        booking: Booking,
+    ) -> Task<AppMessage> {
+        let mut tasks = vec![];
+        if !self.rooms.contains_key(&booking.room_id) {
+            tasks.push(Task::perform(
+                get_room(ApiClient::new(&global_state), booking.room_id),
+                |res| Self::map_result(res, |room| TodayMessage::RoomLoaded(Box::new(room))),
+            ));
+        }
+        for guest_id in booking.guest_ids() {
+            if !self.guests.contains_key(&guest_id) {
+                self.failed_guests.remove(&guest_id);
+                tasks.push(Task::perform(
+                    get_guest(ApiClient::new(&global_state), guest_id),
+                    move |res| match res {
+                        ApiResult::Success(guest) => {
+                            AppMessage::TodayMessage(TodayMessage::GuestLoaded(Box::new(guest)))
+                        }
+                        ApiResult::Forbidden => AppMessage::TokenExpired,
+                        ApiResult::BadRequest(err) | ApiResult::Error(err) => {
+                            println!("Error loading guest: {err}");
+                            AppMessage::TodayMessage(TodayMessage::GuestLoadFailed(guest_id))
+                        }
+                    },
+                ));
+            }
+        }
+        self.bookings.insert(booking.id, booking);
+
+        Task::batch(tasks)
+    }
+
+    fn check_out(
+        &mut self,
+        global_state: Arc<Mutex<GlobalState>>,
+        booking_id: Uuid,
+    ) -> Task<AppMessage> {
+        self.pending_check_out = None;
+
+        Task::perform(
+            check_out_booking(ApiClient::new(&global_state), booking_id),
+            move |res| Self::map_result(res, |_| TodayMessage::CheckedOut(booking_id)),
+        )
+    }
+
+    fn request_cleaning(
+        &self,
+        global_state: Arc<Mutex<GlobalState>>,
+        booking_id: Uuid,
+    ) -> Task<AppMessage> {
+        let Some(booking) = self.bookings.get(&booking_id) else {
+            return Task::none();
+        };
+
+        Task::perform(
+            request_room_cleaning(ApiClient::new(&global_state), booking.room_id),
+            |res| Self::map_result(res, |_| TodayMessage::RoomCleaningRequested),
+        )
+    }
+
+    fn are_guests_loaded(&self, booking: &Booking) -> bool {
+        booking
+            .guest_ids()
+            .iter()
+            .all(|id| self.guests.contains_key(id) || self.failed_guests.contains(id))
+    }
+
+    fn view_summary(&self, booking: &Booking) -> Element<'_, AppMessage> {
+        let room_text = self.rooms.get(&booking.room_id).map_or_else(
+            || "Room ...".to_owned(),
+            |room| format!("Room {}", room.room_number),
+        );
+        let guest_text = match self.guests.get(&booking.main_guest) {
+            Some(guest) => format!("{} {}", guest.first_name, guest.last_name),
+            None if self.failed_guests.contains(&booking.main_guest) => {
+                "Guest could not be loaded".to_owned()
+            }
+            None => "Loading guest ...".to_owned(),
+        };
+
+        column![
+            text!("{room_text}, {guest_text}"),
+            text!(
+                "{} guests, {} - {}, {}",
+                booking.guest_ids().len(),
+                booking.start_date,
+                booking.end_date,
+                booking.status
+            )
+            .size(14),
+        ]
+        .width(360)
+        .into()
+    }
+
+    fn view_arrival(&self, booking: &Booking) -> Element<'_, AppMessage> {
+        let problems = if self.are_guests_loaded(booking) {
+            booking.get_check_in_problems(&self.guests)
+        } else {
+            vec!["Loading guests ...".to_owned()]
+        };
+        let can_check_in = booking.status == BookingStatus::Booked && problems.is_empty();
+        let problems = Column::with_children(
+            problems
+                .into_iter()
+                .map(|problem| text(problem).color(WARNING_COLOR).size(14).into()),
+        );
+
+        column![
+            row![
+                self.view_summary(booking),
+                button("Check in").on_press_maybe(
+                    can_check_in
+                        .then_some(AppMessage::TodayMessage(TodayMessage::CheckIn(booking.id)))
+                ),
+                button("Open").on_press(AppMessage::TodayMessage(TodayMessage::OpenBooking(
+                    booking.id
+                ))),
+            ]
+            .spacing(10)
+            .align_y(Center),
+            problems,
+        ]
+        .into()
+    }
+
+    fn view_balance(&self, booking: &Booking) -> String {
+        match self.rooms.get(&booking.room_id) {
+            Some(room) => format!(
+                "Final balance: {:.2} ({} nights x {:.2})",
+                booking.total_price(room) as f64 / 100.0,
+                booking.nights(),
+                room.price as f64 / 100.0
+            ),
+            None => "Final balance: ...".to_owned(),
+        }
+    }
+
+    fn view_departure(&self, booking: &Booking) -> Element<'_, AppMessage> {
+        let can_check_out = booking.status == BookingStatus::CheckedIn;
+        let confirm = (self.pending_check_out == Some(booking.id)).then(|| {
+            row![
+                text("Check out and mark the room for cleaning?"),
+                button("Confirm").on_press(AppMessage::TodayMessage(
+                    TodayMessage::ConfirmCheckOut(booking.id)
+                )),
+                button("Back").on_press(AppMessage::TodayMessage(TodayMessage::CancelCheckOut)),
+            ]
+            .spacing(10)
+            .align_y(Center)
+        });
+
+        column![
+            row![
+                self.view_summary(booking),
+                button("Check out").on_press_maybe(can_check_out.then_some(
+                    AppMessage::TodayMessage(TodayMessage::StartCheckOut(booking.id))
+                )),
+                button("Open").on_press(AppMessage::TodayMessage(TodayMessage::OpenBooking(
+                    booking.id
+                ))),
+            ]
+            .spacing(10)
+            .align_y(Center),
+            text(self.view_balance(booking)).size(14),
+        ]
+        .push_maybe(confirm)
+        .spacing(5)
+        .into()
+    }
+
+    fn view_list<'a, F>(
+        &'a self,
+        ids: &'a Option<Vec<Uuid>>,
+        empty_text: &'a str,
+        view_booking: F,
+    ) -> Element<'a, AppMessage>
+    where
+        F: Fn(&'a Self, &'a Booking) -> Element<'a, AppMessage>,
+    {
+        let Some(ids) = ids else {
+            return text("Loading ...").into();
+        };
+        // Checked in and out bookings stay listed for the day, cancelled ones are left out
+        let ids: Vec<_> = ids
+            .iter()
+            .filter(|id| {
+                self.bookings
+                    .get(id)
+                    .is_none_or(|booking| booking.status != BookingStatus::Cancelled)
+            })
+            .collect();
+        if ids.is_empty() {
+            return text(empty_text).into();
+        }
+
+        Column::with_children(ids.into_iter().map(|id| match self.bookings.get(id) {
+            Some(booking) => view_booking(self, booking),
+            None => text("Loading booking ...").into(),
+        }))
+        .spacing(10)
+        .into()
+    }
+}
+impl Screen for TodayScreen {
+    fn on_open(&mut self, global_state: Arc<Mutex<GlobalState>>) -> Task<AppMessage> {
+        self.refresh(global_state)
+    }
+
+    fn update(
+        &mut self,
+        message: AppMessage,
+        global_state: Arc<Mutex<GlobalState>>,
+    ) -> Task<AppMessage> {
+        match message {
+            AppMessage::TodayMessage(m) => match m {
+                TodayMessage::Refresh => self.refresh(global_state),
+                TodayMessage::ArrivalsFound(ids) => {
+                    let task = Self::load_bookings(&global_state, &ids);
+                    self.arrival_ids = Some(ids);
+                    task
+                }
+                TodayMessage::DeparturesFound(ids) => {
+                    let task = Self::load_bookings(&global_state, &ids);
+                    self.departure_ids = Some(ids);
+                    task
+                }
+                TodayMessage::BookingLoaded(booking) => {
+                    self.load_booking_details(global_state, *booking)
+                }
+                TodayMessage::RoomLoaded(room) => {
+                    self.rooms.insert(room.id, *room);
+                    Task::none()
+                }
+                TodayMessage::GuestLoaded(guest) => {
+                    self.guests.insert(guest.id, *guest);
+                    Task::none()
+                }
+                TodayMessage::GuestLoadFailed(guest_id) => {
+                    self.failed_guests.insert(guest_id);
+                    Task::none()
+                }
+                TodayMessage::CheckIn(booking_id) => {
+                    self.error = "".to_owned();
+                    Task::perform(
+                        check_in_booking(ApiClient::new(&global_state), booking_id),
+                        move |res| Self::map_result(res, |_| TodayMessage::CheckedIn(booking_id)),
+                    )
+                }
+                TodayMessage::CheckedIn(booking_id) => Task::batch([
+                    Task::done(show_notification(
+                        "Guests checked in",
+                        NotificationType::Success,
+                    )),
+                    Self::load_booking(&global_state, booking_id),
+                ]),
+                TodayMessage::StartCheckOut(booking_id) => {
+                    self.error = "".to_owned();
+                    self.pending_check_out = Some(booking_id);
+                    Task::none()
+                }
+                TodayMessage::CancelCheckOut => {
+                    self.pending_check_out = None;
+                    Task::none()
+                }
+                TodayMessage::ConfirmCheckOut(booking_id) => {
+                    self.check_out(global_state, booking_id)
+                }
+                TodayMessage::CheckedOut(booking_id) => Task::batch([
+                    self.request_cleaning(global_state.clone(), booking_id),
+                    Self::load_booking(&global_state, booking_id),
+                ]),
+                TodayMessage::RoomCleaningRequested => Task::done(show_notification(
+                    "Checked out, room marked for cleaning",
+                    NotificationType::Success,
+                )),
+                TodayMessage::OpenBooking(booking_id) => match self.bookings.get(&booking_id) {
+                    Some(booking) => Task::done(AppMessage::NavigateTo(
+                        ScreenType::BookingDetails(Box::new(booking.clone())),
+                    )),
+                    None => Task::none(),
+                },
+                TodayMessage::SetError(err) => {
+                    self.error = err;
+                    Task::none()
+                }
+            },
+            _ => Task::none(),
+        }
+    }
+
+    fn view(&self, _global_state: Arc<Mutex<GlobalState>>) -> Element<'_, AppMessage> {
+        scrollable(
+            column![
+                text!("Today, {}", Date::today())
+                    .size(TITLE_FONT_SIZE)
+                    .width(Fill)
+                    .align_x(Center),
+                text("Arrivals").size(24),
+                self.view_list(&self.arrival_ids, "No arrivals today", Self::view_arrival),
+                text("Departures").size(24),
+                self.view_list(
+                    &self.departure_ids,
+                    "No departures today",
+                    Self::view_departure
+                ),
+                text!("{}", self.error).color(ERROR_COLOR).size(18),
+                button("Refresh").on_press(AppMessage::TodayMessage(TodayMessage::Refresh)),
+            ]
+            .spacing(FORM_SPACING)
+            .padding(FORM_PADDING),
+        )
+        .into()
+    }
+}
@@ -1,39 +1,213 @@
-use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use std::fmt::{self, Debug};
+
+use jsonwebtoken::{decode, decode_header, jwk::JwkSet, Algorithm, DecodingKey, Validation};
 use serde::Deserialize;
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum Role {
     User,
     Admin,
 }
 
+#[derive(Clone)]
+pub struct JwtVerifier {
+    algorithm: Algorithm,
+    keys: Vec<(Option<String>, DecodingKey)>,
+    // Static keys are the only candidate, so the token's key id is not checked
+    match_key_ids: bool,
+}
+impl JwtVerifier {
+    pub fn from_secret(secret: &str) -> Self {
+        Self {
+            algorithm: Algorithm::HS256,
+            keys: vec![(None, DecodingKey::from_secret(secret.as_bytes()))],
+            match_key_ids: false,
+        }
+    }
+
+    pub fn from_public_key(algorithm: Algorithm, pem: &[u8]) -> Result<Self, String> {
+        let key = match algorithm {
+            Algorithm::RS256 => DecodingKey::from_rsa_pem(pem),
+            Algorithm::ES256 => DecodingKey::from_ec_pem(pem),
+            _ => return Err(format!("Unsupported public key algorithm '{algorithm:?}'")),
+        }
+        .map_err(|err| format!("Invalid public key: {err}"))?;
+
+        Ok(Self {
+            algorithm,
+            keys: vec![(None, key)],
+            match_key_ids: false,
+        })
+    }
+
+    pub fn from_jwks(algorithm: Algorithm, jwks: &JwkSet) -> Result<Self, String> {
+        let keys: Vec<_> = jwks
+            .keys
+            .iter()
+            .filter_map(|jwk| match DecodingKey::from_jwk(jwk) {
+                Ok(key) => Some((jwk.common.key_id.clone(), key)),
+                Err(err) => {
+                    println!("Skipping unusable JWK: {err}");
+                    None
+                }
+            })
+            .collect();
+
+        if keys.is_empty() {
+            return Err("No usable keys in JWKS".to_owned());
+        }
+        Ok(Self {
+            algorithm,
+            keys,
+            match_key_ids: true,
+        })
+    }
+
+    fn find_key(&self, key_id: Option<&str>) -> Option<&DecodingKey> {
+        if !self.match_key_ids {
+            return self.keys.first().map(|(_, key)| key);
+        }
+        let matching = self
+            .keys
+            .iter()
+            .find(|(id, _)| key_id.is_some() && id.as_deref() == key_id);
+        match (matching, key_id) {
+            (Some((_, key)), _) => Some(key),
+            (None, None) if self.keys.len() == 1 => self.keys.first().map(|(_, key)| key),
+            _ => None,
+        }
+    }
+
+    pub fn can_verify(&self, token: &str) -> bool {
+        decode_header(token).is_ok_and(|header| self.find_key(header.kid.as_deref()).is_some())
+    }
+}
+impl Debug for JwtVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JwtVerifier")
+            .field("algorithm", &self.algorithm)
+            .field(
+                "key_ids",
+                &self.keys.iter().map(|(id, _)| id).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct JwtToken {
     #[serde(skip)]
     pub token_string: String,
     pub role: Role,
+    pub exp: u64,
+    #[serde(default)]
+    pub iat: u64,
 }
 impl JwtToken {
-    pub fn new(token: String) -> Option<Self> {
+    pub fn new_unvalidated(token: String) -> Result<Self, String> {
         let key = DecodingKey::from_secret(&[]);
         let mut validation = Validation::new(Algorithm::HS256);
         validation.insecure_disable_signature_validation();
         validation.validate_exp = false;
-        let jwt_token = decode(&token, &key, &validation);
 
-        match jwt_token {
-            Ok(ok) => {
-                let jwt = Self {
-                    token_string: token,
-                    ..ok.claims
-                };
+        match decode::<Self>(&token, &key, &validation) {
+            Ok(ok) => Ok(Self {
+                token_string: token,
+                ..ok.claims
+            }),
+            Err(err) => Err(format!("Invalid token: {err}")),
+        }
+    }
 
-                Some(jwt)
+    pub fn new(token: String, verifier: Option<&JwtVerifier>) -> Result<Self, String> {
+        let jwt_token = match verifier {
+            Some(verifier) => {
+                let header =
+                    decode_header(&token).map_err(|err| format!("Invalid token: {err}"))?;
+                let key = verifier
+                    .find_key(header.kid.as_deref())
+                    .ok_or_else(|| "No key available to verify token".to_owned())?;
+                decode(&token, key, &Validation::new(verifier.algorithm))
+            }
+            None => {
+                let key = DecodingKey::from_secret(&[]);
+                let mut validation = Validation::new(Algorithm::HS256);
+                validation.insecure_disable_signature_validation();
+                decode(&token, &key, &validation)
             }
+        };
+
+        match jwt_token {
+            Ok(ok) => Ok(Self {
+                token_string: token,
+                ..ok.claims
+            }),
             Err(err) => {
                 println!("Error parsing token:{err}");
-                None
+                Err(format!("Token rejected: {err}"))
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde::Serialize;
+
+    use super::*;
+
+    const SECRET: &str = "test-secret";
+
+    #[derive(Serialize)]
+    struct Claims {
+        role: &'static str,
+        exp: u64,
+    }
+
+    fn sign(secret: &str, key_id: Option<&str>) -> String {
+        let header = Header {
+            kid: key_id.map(str::to_owned),
+            ..Header::new(Algorithm::HS256)
+        };
+        let claims = Claims {
+            role: "Admin",
+            exp: jsonwebtoken::get_current_timestamp() + 3600,
+        };
+
+        encode(
+            &header,
+            &claims,
+            &EncodingKey::from_secret(secret.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn static_key_ignores_key_id() {
+        let verifier = JwtVerifier::from_secret(SECRET);
+
+        let token = JwtToken::new(sign(SECRET, Some("key-1")), Some(&verifier)).unwrap();
+        assert_eq!(token.role, Role::Admin);
+        assert!(JwtToken::new(sign(SECRET, None), Some(&verifier)).is_ok());
+    }
+
+    #[test]
+    fn jwks_requires_matching_key_id() {
+        let jwks: JwkSet = serde_json::from_str(
+            r#"{"keys": [{"kty": "oct", "kid": "key-1", "alg": "HS256", "k": "dGVzdC1zZWNyZXQ"}]}"#,
+        )
+        .unwrap();
+        let verifier = JwtVerifier::from_jwks(Algorithm::HS256, &jwks).unwrap();
+
+        assert!(JwtToken::new(sign(SECRET, Some("key-1")), Some(&verifier)).is_ok());
+        assert!(JwtToken::new(sign(SECRET, Some("key-2")), Some(&verifier)).is_err());
+    }
+
+    #[test]
+    fn rejects_wrong_signature() {
+        let verifier = JwtVerifier::from_secret(SECRET);
+
+        assert!(JwtToken::new(sign("other-secret", Some("key-1")), Some(&verifier)).is_err());
+    }
+}
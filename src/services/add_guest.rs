@@ -1,21 +1,14 @@
-use std::{
-    error::Error,
-    sync::{Arc, Mutex},
-};
-
 use iced_aw::date_picker::Date;
-use reqwest::{header, StatusCode};
 use serde::{Deserialize, Serialize};
-use serde_json::to_string;
 use uuid::Uuid;
 
 use crate::{
-    app::GlobalState,
-    constants::{ADD_GUEST_PATH, BASE_URL},
+    constants::ADD_GUEST_PATH,
     model::id_card::{IdCard, IdCardDto},
-    utils::decode_error_response,
 };
 
+use super::api_client::{ApiClient, ApiResult};
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AddGuestInput {
@@ -27,7 +20,7 @@ pub struct AddGuestInput {
     pub phone_number: Option<String>,
     #[serde(skip)]
     id_card_model: Option<IdCard>,
-    id_card: Option<IdCardDto>,
+    pub id_card: Option<IdCardDto>,
 }
 impl AddGuestInput {
     pub fn new(
@@ -55,65 +48,14 @@ pub struct AddGuestOutput {
     pub guest_id: Uuid,
 }
 
-pub enum AddGuestResult {
-    GuestAdded(Uuid),
-    Forbidden,
-    BadRequest(String),
-}
-
-async fn add_guest_request(
-    input: AddGuestInput,
-    token: Option<String>,
-) -> Result<AddGuestResult, Box<dyn Error + Send + Sync>> {
-    let token_string = if let Some(some) = token {
-        some
-    } else {
-        return Ok(AddGuestResult::Forbidden);
-    };
-
-    let url = BASE_URL.to_owned() + ADD_GUEST_PATH;
-    let client = reqwest::Client::new();
-    println!("POST {url}");
-    println!("Input:{input:?}");
-    let result = client
-        .post(url)
-        .header(header::CONTENT_TYPE, "application/json")
-        .bearer_auth(token_string)
-        .body(to_string(&input)?)
-        .send()
-        .await?;
-
-    println!("Response:{result:?}");
+pub async fn add_guest(client: ApiClient, add_guest_input: AddGuestInput) -> ApiResult<Uuid> {
+    println!("Input:{add_guest_input:?}");
 
-    if result.status().is_success() {
-        let add_guest_output: AddGuestOutput = result.json().await?;
-        println!("Added guest '{}'", add_guest_output.guest_id);
-        Ok(AddGuestResult::GuestAdded(add_guest_output.guest_id))
-    } else if result.status() == StatusCode::FORBIDDEN
-        || result.status() == StatusCode::UNAUTHORIZED
-    {
-        Ok(AddGuestResult::Forbidden)
-    } else {
-        Ok(AddGuestResult::BadRequest(
-            decode_error_response(result).await,
-        ))
-    }
-}
-
-pub async fn add_guest(
-    global_state: Arc<Mutex<GlobalState>>,
-    add_guest_input: AddGuestInput,
-) -> Result<AddGuestResult, String> {
-    let token = {
-        let guard = global_state.lock().unwrap();
-        guard.token.as_ref().map(|some| some.token_string.clone())
-    };
-
-    match add_guest_request(add_guest_input, token).await {
-        Ok(res) => Ok(res),
-        Err(err) => {
-            println!("{err}");
-            Err("Unexpected error".to_owned())
-        }
-    }
+    client
+        .post(ADD_GUEST_PATH, &add_guest_input)
+        .await
+        .map(|output: AddGuestOutput| {
+            println!("Added guest '{}'", output.guest_id);
+            output.guest_id
+        })
 }
@@ -0,0 +1,328 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use jsonwebtoken::{jwk::JwkSet, Algorithm};
+use reqwest::{header, Method, Response, StatusCode};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    app::GlobalState,
+    config::JwtKeySource,
+    security::{JwtToken, JwtVerifier},
+    utils::decode_error_response,
+};
+
+use super::refresh_token::refresh_token;
+
+#[derive(Debug)]
+pub enum ApiResult<T> {
+    Success(T),
+    Forbidden,
+    BadRequest(String),
+    Error(String),
+}
+impl<T> ApiResult<T> {
+    pub fn map<U, F>(self, f: F) -> ApiResult<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            ApiResult::Success(ok) => ApiResult::Success(f(ok)),
+            ApiResult::Forbidden => ApiResult::Forbidden,
+            ApiResult::BadRequest(err) => ApiResult::BadRequest(err),
+            ApiResult::Error(err) => ApiResult::Error(err),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiClient {
+    client: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+    global_state: Arc<Mutex<GlobalState>>,
+    refresh_on_forbidden: bool,
+}
+impl ApiClient {
+    pub fn new(global_state: &Arc<Mutex<GlobalState>>) -> Self {
+        let lock = global_state.lock().unwrap();
+        Self {
+            client: lock.http_client.clone(),
+            base_url: lock.base_url(),
+            token: lock.token.as_ref().map(|jwt| jwt.token_string.clone()),
+            global_state: global_state.clone(),
+            refresh_on_forbidden: true,
+        }
+    }
+
+    pub fn without_refresh(self) -> Self {
+        Self {
+            refresh_on_forbidden: false,
+            ..self
+        }
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    async fn map_response(response: Response) -> ApiResult<Response> {
+        let status = response.status();
+        if status.is_success() {
+            ApiResult::Success(response)
+        } else if status == StatusCode::FORBIDDEN || status == StatusCode::UNAUTHORIZED {
+            ApiResult::Forbidden
+        } else if status.is_client_error() {
+            ApiResult::BadRequest(decode_error_response(response).await)
+        } else {
+            ApiResult::Error(format!(
+                "Unexpected server error '{status}': {}",
+                decode_error_response(response).await
+            ))
+        }
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<&str>,
+        token: Option<&str>,
+    ) -> ApiResult<Response> {
+        let url = self.base_url.clone() + path;
+        println!("{method} {url}");
+
+        let mut request = self
+            .client
+            .request(method, url)
+            .query(query)
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        if let Some(body) = body {
+            request = request.body(body.to_owned());
+        }
+
+        match request.send().await {
+            Ok(response) => {
+                println!("Response status: {}", response.status());
+                Self::map_response(response).await
+            }
+            Err(err) => ApiResult::Error(err.to_string()),
+        }
+    }
+
+    fn refresh<'a>(
+        &'a self,
+        expired_token: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Option<String>> + Send + 'a>> {
+        Box::pin(async move {
+            let current_token = self
+                .global_state
+                .lock()
+                .unwrap()
+                .token
+                .as_ref()
+                .map(|jwt| jwt.token_string.clone());
+            match current_token {
+                Some(current) if current != expired_token => return Some(current),
+                Some(_) => {}
+                None => return None,
+            }
+
+            match refresh_token(self.clone()).await {
+                ApiResult::Success(jwt) => {
+                    println!("Refreshed expired token");
+                    let token_string = jwt.token_string.clone();
+                    let mut lock = self.global_state.lock().unwrap();
+                    if lock
+                        .token
+                        .as_ref()
+                        .is_some_and(|current| current.token_string == expired_token)
+                    {
+                        lock.set_token(jwt);
+                    }
+                    Some(token_string)
+                }
+                ApiResult::Forbidden => {
+                    println!("Error refreshing token: forbidden");
+                    None
+                }
+                ApiResult::BadRequest(err) | ApiResult::Error(err) => {
+                    println!("Error refreshing token: {err}");
+                    None
+                }
+            }
+        })
+    }
+
+    async fn execute<I>(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<&I>,
+    ) -> ApiResult<Response>
+    where
+        I: Serialize,
+    {
+        let body = match body.map(serde_json::to_string).transpose() {
+            Ok(ok) => ok,
+            Err(err) => return ApiResult::Error(format!("Error encoding request: {err}")),
+        };
+
+        let result = self
+            .send(
+                method.clone(),
+                path,
+                query,
+                body.as_deref(),
+                self.token.as_deref(),
+            )
+            .await;
+
+        match (result, &self.token) {
+            (ApiResult::Forbidden, Some(token)) if self.refresh_on_forbidden => {
+                match self.refresh(token).await {
+                    Some(new_token) => {
+                        println!("Retrying request with refreshed token");
+                        self.send(method, path, query, body.as_deref(), Some(&new_token))
+                            .await
+                    }
+                    None => ApiResult::Forbidden,
+                }
+            }
+            (result, _) => result,
+        }
+    }
+
+    async fn decode<T>(result: ApiResult<Response>) -> ApiResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        let response = match result {
+            ApiResult::Success(response) => response,
+            ApiResult::Forbidden => return ApiResult::Forbidden,
+            ApiResult::BadRequest(err) => return ApiResult::BadRequest(err),
+            ApiResult::Error(err) => return ApiResult::Error(err),
+        };
+
+        match response.json().await {
+            Ok(ok) => ApiResult::Success(ok),
+            Err(err) => ApiResult::Error(format!("Error decoding response: {err}")),
+        }
+    }
+
+    pub async fn get<T>(&self, path: &str, query: &[(&str, String)]) -> ApiResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        Self::decode(self.execute::<()>(Method::GET, path, query, None).await).await
+    }
+
+    pub async fn post<I, T>(&self, path: &str, body: &I) -> ApiResult<T>
+    where
+        I: Serialize,
+        T: DeserializeOwned,
+    {
+        Self::decode(self.execute(Method::POST, path, &[], Some(body)).await).await
+    }
+
+    pub async fn post_no_content<I>(&self, path: &str, body: &I) -> ApiResult<()>
+    where
+        I: Serialize,
+    {
+        self.execute(Method::POST, path, &[], Some(body))
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn put_no_content<I>(&self, path: &str, body: &I) -> ApiResult<()>
+    where
+        I: Serialize,
+    {
+        self.execute(Method::PUT, path, &[], Some(body))
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn patch_no_content<I>(&self, path: &str, body: &I) -> ApiResult<()>
+    where
+        I: Serialize,
+    {
+        self.execute(Method::PATCH, path, &[], Some(body))
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn delete(&self, path: &str) -> ApiResult<()> {
+        self.execute::<()>(Method::DELETE, path, &[], None)
+            .await
+            .map(|_| ())
+    }
+
+    async fn jwks_verifier(
+        &self,
+        algorithm: Algorithm,
+        url: &str,
+        token: &str,
+    ) -> ApiResult<JwtVerifier> {
+        let cached = self.global_state.lock().unwrap().jwks_verifier.clone();
+        if let Some(verifier) = cached.filter(|verifier| verifier.can_verify(token)) {
+            return ApiResult::Success(verifier);
+        }
+
+        // The JWKS may be served by a third party, so it is fetched without the user's token
+        println!("GET {url}");
+        let jwks: JwkSet = match self.client.get(url).send().await {
+            Ok(response) => match Self::decode(Self::map_response(response).await).await {
+                ApiResult::Success(ok) => ok,
+                ApiResult::Forbidden => return ApiResult::Error("JWKS access denied".to_owned()),
+                ApiResult::BadRequest(err) | ApiResult::Error(err) => return ApiResult::Error(err),
+            },
+            Err(err) => return ApiResult::Error(err.to_string()),
+        };
+
+        match JwtVerifier::from_jwks(algorithm, &jwks) {
+            Ok(verifier) => {
+                self.global_state.lock().unwrap().jwks_verifier = Some(verifier.clone());
+                ApiResult::Success(verifier)
+            }
+            Err(err) => ApiResult::Error(err),
+        }
+    }
+
+    pub async fn verify_token(&self, token: String) -> ApiResult<JwtToken> {
+        let key_source = self.global_state.lock().unwrap().config.jwt_key.clone();
+        let verifier = match key_source {
+            Some(JwtKeySource::Static(verifier)) => Some(verifier),
+            Some(JwtKeySource::Jwks { algorithm, url }) => {
+                match self.jwks_verifier(algorithm, &url, &token).await {
+                    ApiResult::Success(verifier) => Some(verifier),
+                    ApiResult::Forbidden => return ApiResult::Forbidden,
+                    ApiResult::BadRequest(err) | ApiResult::Error(err) => {
+                        println!("Error loading JWKS: {err}");
+                        return ApiResult::Error(format!("Could not load token keys: {err}"));
+                    }
+                }
+            }
+            None => None,
+        };
+
+        match JwtToken::new(token, verifier.as_ref()) {
+            Ok(jwt) => ApiResult::Success(jwt),
+            Err(err) => ApiResult::BadRequest(err),
+        }
+    }
+
+    pub async fn post_empty(&self, path: &str) -> ApiResult<()> {
+        self.execute::<()>(Method::POST, path, &[], None)
+            .await
+            .map(|_| ())
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::constants::CANCEL_BOOKING_PATH;
+
+use super::api_client::{ApiClient, ApiResult};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelBookingInput {
+    pub reason: String,
+}
+
+pub async fn cancel_booking(
+    client: ApiClient,
+    booking_id: Uuid,
+    input: CancelBookingInput,
+) -> ApiResult<()> {
+    let path = CANCEL_BOOKING_PATH.to_owned() + &booking_id.to_string() + "/cancel";
+
+    client.post_no_content(&path, &input).await
+}
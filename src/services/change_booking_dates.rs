@@ -0,0 +1,32 @@
+use iced_aw::date_picker::Date;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::constants::CHANGE_BOOKING_DATES_PATH;
+
+use super::api_client::{ApiClient, ApiResult};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeBookingDatesInput {
+    pub start_date: String,
+    pub end_date: String,
+}
+impl ChangeBookingDatesInput {
+    pub fn new(start_date: Date, end_date: Date) -> Self {
+        Self {
+            start_date: start_date.to_string(),
+            end_date: end_date.to_string(),
+        }
+    }
+}
+
+pub async fn change_booking_dates(
+    client: ApiClient,
+    booking_id: Uuid,
+    input: ChangeBookingDatesInput,
+) -> ApiResult<()> {
+    let path = CHANGE_BOOKING_DATES_PATH.to_owned() + &booking_id.to_string() + "/dates";
+
+    client.put_no_content(&path, &input).await
+}
@@ -0,0 +1,11 @@
+use uuid::Uuid;
+
+use crate::constants::CHECK_IN_BOOKING_PATH;
+
+use super::api_client::{ApiClient, ApiResult};
+
+pub async fn check_in_booking(client: ApiClient, booking_id: Uuid) -> ApiResult<()> {
+    let path = CHECK_IN_BOOKING_PATH.to_owned() + &booking_id.to_string() + "/check-in";
+
+    client.post_empty(&path).await
+}
@@ -0,0 +1,11 @@
+use uuid::Uuid;
+
+use crate::constants::CHECK_OUT_BOOKING_PATH;
+
+use super::api_client::{ApiClient, ApiResult};
+
+pub async fn check_out_booking(client: ApiClient, booking_id: Uuid) -> ApiResult<()> {
+    let path = CHECK_OUT_BOOKING_PATH.to_owned() + &booking_id.to_string() + "/check-out";
+
+    client.post_empty(&path).await
+}
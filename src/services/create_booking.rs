@@ -0,0 +1,52 @@
+use iced_aw::date_picker::Date;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::constants::CREATE_BOOKING_PATH;
+
+use super::api_client::{ApiClient, ApiResult};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBookingInput {
+    pub room_id: Uuid,
+    pub main_guest: Uuid,
+    pub other_guests: Vec<Uuid>,
+    pub start_date: String,
+    pub end_date: String,
+}
+impl CreateBookingInput {
+    pub fn new(
+        room_id: Uuid,
+        main_guest: Uuid,
+        other_guests: Vec<Uuid>,
+        start_date: Date,
+        end_date: Date,
+    ) -> Self {
+        Self {
+            room_id,
+            main_guest,
+            other_guests,
+            start_date: start_date.to_string(),
+            end_date: end_date.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateBookingOutput {
+    booking_id: Uuid,
+}
+
+pub async fn create_booking(client: ApiClient, input: CreateBookingInput) -> ApiResult<Uuid> {
+    println!("Input:{input:?}");
+
+    client
+        .post(CREATE_BOOKING_PATH, &input)
+        .await
+        .map(|output: CreateBookingOutput| {
+            println!("Created booking '{}'", output.booking_id);
+            output.booking_id
+        })
+}
@@ -0,0 +1,11 @@
+use uuid::Uuid;
+
+use crate::constants::DELETE_ROOM_PATH;
+
+use super::api_client::{ApiClient, ApiResult};
+
+pub async fn delete_room(client: ApiClient, room_id: Uuid) -> ApiResult<()> {
+    let path = DELETE_ROOM_PATH.to_owned() + &room_id.to_string();
+
+    client.delete(&path).await
+}
@@ -0,0 +1,95 @@
+use iced_aw::date_picker::Date;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{constants::FIND_BOOKINGS_PATH, model::booking_status::BookingStatus};
+
+use super::api_client::{ApiClient, ApiResult};
+
+#[derive(Debug, Clone, Default)]
+pub struct FindBookingsInput {
+    pub starts_on: Option<Date>,
+    pub ends_on: Option<Date>,
+    pub from: Option<Date>,
+    pub to: Option<Date>,
+    pub room_id: Option<Uuid>,
+    pub guest_id: Option<Uuid>,
+    pub status: Option<BookingStatus>,
+    pub mine: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FindBookingsOutput {
+    booking_ids: Vec<Uuid>,
+}
+
+fn convert_input(input: FindBookingsInput) -> Vec<(&'static str, String)> {
+    let mut query_params = vec![];
+
+    if let Some(date) = input.starts_on {
+        query_params.push(("startDate", date.to_string()));
+    }
+    if let Some(date) = input.ends_on {
+        query_params.push(("endDate", date.to_string()));
+    }
+    if let Some(date) = input.from {
+        query_params.push(("from", date.to_string()));
+    }
+    if let Some(date) = input.to {
+        query_params.push(("to", date.to_string()));
+    }
+    if let Some(room_id) = input.room_id {
+        query_params.push(("roomId", room_id.to_string()));
+    }
+    if let Some(guest_id) = input.guest_id {
+        query_params.push(("guestId", guest_id.to_string()));
+    }
+    if let Some(status) = input.status {
+        let status = match status {
+            BookingStatus::Booked => "Booked",
+            BookingStatus::CheckedIn => "CheckedIn",
+            BookingStatus::CheckedOut => "CheckedOut",
+            BookingStatus::Cancelled => "Cancelled",
+        };
+        query_params.push(("status", status.to_owned()));
+    }
+    if input.mine {
+        query_params.push(("mine", "true".to_owned()));
+    }
+
+    query_params
+}
+
+pub async fn find_bookings(client: ApiClient, input: FindBookingsInput) -> ApiResult<Vec<Uuid>> {
+    let query_params = convert_input(input);
+
+    client
+        .get(FIND_BOOKINGS_PATH, &query_params)
+        .await
+        .map(|output: FindBookingsOutput| output.booking_ids)
+}
+
+pub async fn find_bookings_matching_any(
+    client: ApiClient,
+    inputs: Vec<FindBookingsInput>,
+) -> ApiResult<Vec<Uuid>> {
+    let mut booking_ids: Vec<Uuid> = vec![];
+
+    for input in inputs {
+        match find_bookings(client.clone(), input).await {
+            ApiResult::Success(ids) => {
+                for id in ids {
+                    if !booking_ids.contains(&id) {
+                        booking_ids.push(id);
+                    }
+                }
+            }
+            ApiResult::Forbidden => return ApiResult::Forbidden,
+            ApiResult::BadRequest(err) => return ApiResult::BadRequest(err),
+            ApiResult::Error(err) => return ApiResult::Error(err),
+        }
+    }
+
+    ApiResult::Success(booking_ids)
+}
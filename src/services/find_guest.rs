@@ -1,19 +1,11 @@
-use std::{
-    error::Error,
-    sync::{Arc, Mutex},
-};
-
-use reqwest::{header, StatusCode};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{
-    app::GlobalState,
-    constants::{BASE_URL, FIND_GUEST_PATH},
-    utils::decode_error_response,
-};
+use crate::constants::FIND_GUEST_PATH;
+
+use super::api_client::{ApiClient, ApiResult};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FindGuestInput {
     pub first_name: Option<String>,
@@ -21,6 +13,7 @@ pub struct FindGuestInput {
     pub date_of_birth: Option<String>,
     pub ucn: Option<String>,
     pub phone_number: Option<String>,
+    pub id_card_valid_before: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -29,12 +22,6 @@ pub struct FindGuestOutput {
     guest_ids: Vec<Uuid>,
 }
 
-pub enum FindGuestResult {
-    Found(Vec<Uuid>),
-    BadRequest(String),
-    Forbidden,
-}
-
 fn convert_input(input: FindGuestInput) -> Vec<(&'static str, String)> {
     let mut query_params = vec![];
 
@@ -56,53 +43,70 @@ fn convert_input(input: FindGuestInput) -> Vec<(&'static str, String)> {
 
     input.ucn.map(|ucn| query_params.push(("ucn", ucn)));
 
+    if let Some(date) = input.id_card_valid_before {
+        query_params.push(("idCardValidBefore", date));
+    }
+
     query_params
 }
 
-async fn find_guest_request(
-    token: String,
-    input: FindGuestInput,
-) -> Result<FindGuestResult, Box<dyn Error + Send + Sync>> {
-    let url = BASE_URL.to_owned() + FIND_GUEST_PATH;
-    println!("GET {url}");
+pub fn search_queries(guest: &str) -> Vec<FindGuestInput> {
+    if guest.chars().all(|c| c.is_ascii_digit()) {
+        return vec![FindGuestInput {
+            ucn: Some(guest.to_owned()),
+            ..Default::default()
+        }];
+    }
+
+    let names: Vec<_> = guest.split_whitespace().collect();
+    match names.as_slice() {
+        [first_name, last_name] => vec![FindGuestInput {
+            first_name: Some(first_name.to_string()),
+            last_name: Some(last_name.to_string()),
+            ..Default::default()
+        }],
+        _ => vec![
+            FindGuestInput {
+                first_name: Some(guest.to_owned()),
+                ..Default::default()
+            },
+            FindGuestInput {
+                last_name: Some(guest.to_owned()),
+                ..Default::default()
+            },
+        ],
+    }
+}
 
-    let client = reqwest::Client::new();
-    println!("GET {url}");
+pub async fn find_guest(client: ApiClient, input: FindGuestInput) -> ApiResult<Vec<Uuid>> {
     let query_params = convert_input(input);
 
-    let result = client
-        .get(url)
-        .query(&query_params)
-        .header(header::CONTENT_TYPE, "application/json")
-        .bearer_auth(token)
-        .send()
-        .await?;
-
-    let status = result.status();
-    if status.is_success() {
-        let output: FindGuestOutput = result.json().await?;
-        Ok(FindGuestResult::Found(output.guest_ids))
-    } else if status == StatusCode::FORBIDDEN || status == StatusCode::UNAUTHORIZED {
-        Ok(FindGuestResult::Forbidden)
-    } else {
-        Ok(FindGuestResult::BadRequest(
-            decode_error_response(result).await,
-        ))
-    }
+    client
+        .get(FIND_GUEST_PATH, &query_params)
+        .await
+        .map(|output: FindGuestOutput| output.guest_ids)
 }
 
-pub async fn find_guest(
-    global_state: Arc<Mutex<GlobalState>>,
-    input: FindGuestInput,
-) -> Result<FindGuestResult, String> {
-    let token = if let Some(some) = global_state.lock().unwrap().token.as_ref() {
-        some.token_string.clone()
-    } else {
-        return Ok(FindGuestResult::Forbidden);
-    };
-
-    match find_guest_request(token, input).await {
-        Ok(ok) => Ok(ok),
-        Err(err) => Err(err.to_string()),
+pub async fn find_guests_matching_any(
+    client: ApiClient,
+    inputs: Vec<FindGuestInput>,
+) -> ApiResult<Vec<Uuid>> {
+    let mut guest_ids: Vec<Uuid> = vec![];
+
+    for input in inputs {
+        match find_guest(client.clone(), input).await {
+            ApiResult::Success(ids) => {
+                for id in ids {
+                    if !guest_ids.contains(&id) {
+                        guest_ids.push(id);
+                    }
+                }
+            }
+            ApiResult::Forbidden => return ApiResult::Forbidden,
+            ApiResult::BadRequest(err) => return ApiResult::BadRequest(err),
+            ApiResult::Error(err) => return ApiResult::Error(err),
+        }
     }
+
+    ApiResult::Success(guest_ids)
 }
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::constants::FIND_ROOMS_PATH;
+
+use super::api_client::{ApiClient, ApiResult};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FindRoomsOutput {
+    room_ids: Vec<Uuid>,
+}
+
+pub async fn find_rooms(client: ApiClient) -> ApiResult<Vec<Uuid>> {
+    client
+        .get(FIND_ROOMS_PATH, &[])
+        .await
+        .map(|output: FindRoomsOutput| output.room_ids)
+}
@@ -1,25 +1,101 @@
 use std::{
-    error::Error,
-    sync::{Arc, Mutex},
+    borrow::Borrow,
+    fmt::{self, Display},
 };
 
+use iced::futures::{stream, StreamExt};
 use iced_aw::date_picker::Date;
-use reqwest::{header, Response, StatusCode};
 use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::{
-    app::GlobalState,
-    constants::{BASE_URL, FIND_UNOCCUPIED_ROOMS_PATH},
-    utils::decode_error_response,
+    constants::FIND_UNOCCUPIED_ROOMS_PATH,
+    model::{bathroom_type::BathroomType, bed::BedSize, room::Room},
 };
 
+use super::{
+    api_client::{ApiClient, ApiResult},
+    get_room::get_room,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoomOrder {
+    #[default]
+    Unsorted,
+    Price,
+    Floor,
+    Capacity,
+}
+impl Display for RoomOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoomOrder::Unsorted => write!(f, "No sorting"),
+            RoomOrder::Price => write!(f, "Sort by price"),
+            RoomOrder::Floor => write!(f, "Sort by floor"),
+            RoomOrder::Capacity => write!(f, "Sort by capacity"),
+        }
+    }
+}
+impl RoomOrder {
+    pub fn sort<R: Borrow<Room>>(&self, rooms: &mut [R]) {
+        match self {
+            RoomOrder::Unsorted => {}
+            RoomOrder::Price => rooms.sort_by_key(|room| room.borrow().price),
+            RoomOrder::Floor => rooms.sort_by_key(|room| room.borrow().floor),
+            RoomOrder::Capacity => {
+                rooms.sort_by_key(|room| (room.borrow().capacity(), room.borrow().price))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RoomFilters {
+    pub bathroom_type: Option<BathroomType>,
+    pub bed_size: Option<BedSize>,
+    pub minimum_floor: Option<i16>,
+    pub maximum_floor: Option<i16>,
+    pub minimum_price: Option<i64>,
+    pub maximum_price: Option<i64>,
+    pub order: RoomOrder,
+}
+impl RoomFilters {
+    fn needs_rooms(&self) -> bool {
+        self.bathroom_type.is_some()
+            || self.bed_size.is_some()
+            || self.minimum_floor.is_some()
+            || self.maximum_floor.is_some()
+            || self.minimum_price.is_some()
+            || self.maximum_price.is_some()
+            || self.order != RoomOrder::Unsorted
+    }
+
+    fn matches(&self, room: &Room) -> bool {
+        self.bathroom_type
+            .is_none_or(|bathroom_type| room.bathroom_type == bathroom_type)
+            && self
+                .bed_size
+                .is_none_or(|bed_size| room.beds.iter().any(|bed| bed.bed_size == bed_size))
+            && self.minimum_floor.is_none_or(|floor| room.floor >= floor)
+            && self.maximum_floor.is_none_or(|floor| room.floor <= floor)
+            && self.minimum_price.is_none_or(|price| room.price >= price)
+            && self.maximum_price.is_none_or(|price| room.price <= price)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FindUnoccupiedRoomsInput {
     pub start_date: Date,
     pub end_date: Date,
     pub minimum_capacity: Option<i16>,
     pub maximum_capacity: Option<i16>,
+    pub filters: RoomFilters,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UnoccupiedRooms {
+    pub ids: Vec<Uuid>,
+    pub loaded: Vec<Room>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -28,28 +104,6 @@ struct FindUnoccupiedRoomsOutput {
     room_ids: Vec<Uuid>,
 }
 
-pub enum FindUnoccupiedRoomsResult {
-    Found(Vec<Uuid>),
-    BadRequest(String),
-    Forbidden,
-}
-
-async fn map_response(
-    response: Response,
-) -> Result<FindUnoccupiedRoomsResult, Box<dyn Error + Send + Sync>> {
-    Ok(if response.status().is_success() {
-        let output: FindUnoccupiedRoomsOutput = response.json().await?;
-        println!("Found results '{}'", output.room_ids.len());
-        FindUnoccupiedRoomsResult::Found(output.room_ids)
-    } else if response.status() == StatusCode::FORBIDDEN
-        || response.status() == StatusCode::UNAUTHORIZED
-    {
-        FindUnoccupiedRoomsResult::Forbidden
-    } else {
-        FindUnoccupiedRoomsResult::BadRequest(decode_error_response(response).await)
-    })
-}
-
 fn convert_input(input: FindUnoccupiedRoomsInput) -> Vec<(&'static str, String)> {
     let mut query_params = vec![
         ("startDate", input.start_date.to_string()),
@@ -67,46 +121,96 @@ fn convert_input(input: FindUnoccupiedRoomsInput) -> Vec<(&'static str, String)>
     query_params
 }
 
-async fn find_unoccupied_rooms_request(
-    token: String,
-    input: FindUnoccupiedRoomsInput,
-) -> Result<FindUnoccupiedRoomsResult, Box<dyn Error + Send + Sync>> {
-    let url = BASE_URL.to_owned() + FIND_UNOCCUPIED_ROOMS_PATH;
-    let client = reqwest::Client::new();
-    println!("GET {url}");
-    let query_params = convert_input(input);
-
-    let result = client
-        .get(url)
-        .query(&query_params)
-        .header(header::CONTENT_TYPE, "application/json")
-        .bearer_auth(token)
-        .send()
-        .await?;
-
-    println!("Response:{result:?}");
-
-    map_response(result).await
+// The backend only filters by dates and capacity, the remaining filters
+// are applied to the loaded rooms. They keep the backend order, callers sort them
+async fn filter_rooms(
+    client: ApiClient,
+    room_ids: Vec<Uuid>,
+    filters: RoomFilters,
+) -> ApiResult<UnoccupiedRooms> {
+    const CONCURRENT_LOADS: usize = 8;
+    let results: Vec<_> = stream::iter(room_ids)
+        .map(|room_id| get_room(client.clone(), room_id))
+        .buffered(CONCURRENT_LOADS)
+        .collect()
+        .await;
+
+    let mut rooms = vec![];
+    for result in results {
+        match result {
+            ApiResult::Success(room) if filters.matches(&room) => rooms.push(room),
+            ApiResult::Success(_) => {}
+            ApiResult::Forbidden => return ApiResult::Forbidden,
+            ApiResult::BadRequest(err) => return ApiResult::BadRequest(err),
+            ApiResult::Error(err) => return ApiResult::Error(err),
+        }
+    }
+    ApiResult::Success(UnoccupiedRooms {
+        ids: rooms.iter().map(|room| room.id).collect(),
+        loaded: rooms,
+    })
 }
 
 pub async fn find_unoccupied_rooms(
-    global_state: Arc<Mutex<GlobalState>>,
+    client: ApiClient,
     input: FindUnoccupiedRoomsInput,
-) -> Result<FindUnoccupiedRoomsResult, String> {
-    let token = if let Some(some_token) = global_state
-        .lock()
-        .unwrap()
-        .token
-        .as_ref()
-        .map(|x| x.token_string.clone())
+) -> ApiResult<Vec<Uuid>> {
+    search_unoccupied_rooms(client, input)
+        .await
+        .map(|rooms| rooms.ids)
+}
+
+// Also returns the rooms that had to be loaded for filtering, so they are not
+// fetched again when shown
+pub async fn search_unoccupied_rooms(
+    client: ApiClient,
+    input: FindUnoccupiedRoomsInput,
+) -> ApiResult<UnoccupiedRooms> {
+    let filters = input.filters.clone();
+    let query_params = convert_input(input);
+
+    let room_ids = match client
+        .get(FIND_UNOCCUPIED_ROOMS_PATH, &query_params)
+        .await
+        .map(|output: FindUnoccupiedRoomsOutput| output.room_ids)
     {
-        some_token
-    } else {
-        return Ok(FindUnoccupiedRoomsResult::Forbidden);
+        ApiResult::Success(room_ids) => room_ids,
+        ApiResult::Forbidden => return ApiResult::Forbidden,
+        ApiResult::BadRequest(err) => return ApiResult::BadRequest(err),
+        ApiResult::Error(err) => return ApiResult::Error(err),
     };
+    println!("Found results '{}'", room_ids.len());
+    if !filters.needs_rooms() {
+        return ApiResult::Success(UnoccupiedRooms {
+            ids: room_ids,
+            loaded: vec![],
+        });
+    }
+
+    filter_rooms(client, room_ids, filters).await
+}
 
-    match find_unoccupied_rooms_request(token, input).await {
-        Ok(ok) => Ok(ok),
-        Err(err) => Err(err.to_string()),
+pub async fn is_room_unoccupied(
+    client: ApiClient,
+    room_id: Uuid,
+    date_ranges: Vec<(Date, Date)>,
+) -> ApiResult<bool> {
+    for (start_date, end_date) in date_ranges {
+        let input = FindUnoccupiedRoomsInput {
+            start_date,
+            end_date,
+            minimum_capacity: None,
+            maximum_capacity: None,
+            filters: RoomFilters::default(),
+        };
+        match find_unoccupied_rooms(client.clone(), input).await {
+            ApiResult::Success(ids) if !ids.contains(&room_id) => return ApiResult::Success(false),
+            ApiResult::Success(_) => {}
+            ApiResult::Forbidden => return ApiResult::Forbidden,
+            ApiResult::BadRequest(err) => return ApiResult::BadRequest(err),
+            ApiResult::Error(err) => return ApiResult::Error(err),
+        }
     }
+
+    ApiResult::Success(true)
 }
@@ -0,0 +1,17 @@
+use uuid::Uuid;
+
+use crate::{
+    constants::GET_BOOKING_PATH,
+    model::booking::{Booking, BookingDto},
+};
+
+use super::api_client::{ApiClient, ApiResult};
+
+pub async fn get_booking(client: ApiClient, booking_id: Uuid) -> ApiResult<Booking> {
+    let path = GET_BOOKING_PATH.to_owned() + &booking_id.to_string();
+
+    client
+        .get(&path, &[])
+        .await
+        .map(|booking_dto: BookingDto| booking_dto.convert_with_id(booking_id))
+}
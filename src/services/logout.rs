@@ -1,39 +1,11 @@
-use std::error::Error;
+use crate::constants::LOGOUT_PATH;
 
-use reqwest::header;
+use super::api_client::{ApiClient, ApiResult};
 
-use crate::constants::{BASE_URL, LOGOUT_PATH};
-
-async fn logout_request(token: Option<String>) -> Result<(), Box<dyn Error>> {
-    let url = BASE_URL.to_owned() + LOGOUT_PATH;
-    let client = reqwest::Client::new();
-    println!("POST {url}");
-    let result = client
-        .post(url)
-        .header(header::CONTENT_TYPE, "application/json")
-        .bearer_auth(token.unwrap_or_default())
-        .send()
-        .await?;
-
-    if result.status().is_success() {
-        Ok(())
-    } else {
-        let error_message = result.text().await?;
-        Err(format!("Failed to logout: {}", error_message).into())
-    }
-}
-
-pub async fn logout(token: Option<String>) -> Result<(), String> {
-    if token.is_none() {
-        return Ok(());
+pub async fn logout(client: ApiClient) -> ApiResult<()> {
+    if !client.has_token() {
+        return ApiResult::Success(());
     }
 
-    let result = logout_request(token).await;
-    match result {
-        Ok(_) => Ok(()),
-        Err(err) => {
-            println!("{err}");
-            Err("Failed to logout".to_owned())
-        }
-    }
+    client.without_refresh().post_empty(LOGOUT_PATH).await
 }
@@ -1,12 +1,27 @@
 pub mod add_guest;
 pub mod add_room;
+pub mod api_client;
+pub mod cancel_booking;
+pub mod change_booking_dates;
+pub mod check_in_booking;
+pub mod check_out_booking;
+pub mod create_booking;
+pub mod delete_room;
+pub mod find_bookings;
 pub mod find_guest;
+pub mod find_rooms;
 pub mod find_unoccupied_rooms;
+pub mod get_booking;
 pub mod get_guest;
 pub mod get_room;
 pub mod login;
 pub mod logout;
+pub mod move_booking;
 pub mod refresh_token;
 pub mod register;
+pub mod request_room_cleaning;
 pub mod reset_password;
 pub mod send_otp;
+pub mod update_booking_guests;
+pub mod update_guest;
+pub mod update_room;
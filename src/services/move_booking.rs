@@ -0,0 +1,22 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::constants::MOVE_BOOKING_PATH;
+
+use super::api_client::{ApiClient, ApiResult};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveBookingInput {
+    pub room_id: Uuid,
+}
+
+pub async fn move_booking(
+    client: ApiClient,
+    booking_id: Uuid,
+    input: MoveBookingInput,
+) -> ApiResult<()> {
+    let path = MOVE_BOOKING_PATH.to_owned() + &booking_id.to_string() + "/room";
+
+    client.put_no_content(&path, &input).await
+}
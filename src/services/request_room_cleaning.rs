@@ -0,0 +1,11 @@
+use uuid::Uuid;
+
+use crate::constants::REQUEST_ROOM_CLEANING_PATH;
+
+use super::api_client::{ApiClient, ApiResult};
+
+pub async fn request_room_cleaning(client: ApiClient, room_id: Uuid) -> ApiResult<()> {
+    let path = REQUEST_ROOM_CLEANING_PATH.to_owned() + &room_id.to_string() + "/cleaning";
+
+    client.post_empty(&path).await
+}
@@ -0,0 +1,23 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::constants::UPDATE_BOOKING_GUESTS_PATH;
+
+use super::api_client::{ApiClient, ApiResult};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBookingGuestsInput {
+    pub main_guest: Uuid,
+    pub other_guests: Vec<Uuid>,
+}
+
+pub async fn update_booking_guests(
+    client: ApiClient,
+    booking_id: Uuid,
+    input: UpdateBookingGuestsInput,
+) -> ApiResult<()> {
+    let path = UPDATE_BOOKING_GUESTS_PATH.to_owned() + &booking_id.to_string() + "/guests";
+
+    client.put_no_content(&path, &input).await
+}
@@ -0,0 +1,61 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    constants::UPDATE_GUEST_PATH,
+    model::{guest::Guest, id_card::IdCardDto},
+};
+
+use super::{
+    add_guest::AddGuestInput,
+    api_client::{ApiClient, ApiResult},
+};
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateGuestInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_of_birth: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_card: Option<Option<IdCardDto>>,
+}
+impl UpdateGuestInput {
+    pub fn from_changes(original: &Guest, updated: AddGuestInput) -> Self {
+        let original_id_card: Option<IdCardDto> = original.id_card.clone().map(|x| x.into());
+
+        Self {
+            first_name: (updated.first_name != original.first_name).then_some(updated.first_name),
+            last_name: (updated.last_name != original.last_name).then_some(updated.last_name),
+            date_of_birth: (updated.date_of_birth != original.date_of_birth.to_string())
+                .then_some(updated.date_of_birth),
+            phone_number: (updated.phone_number != original.phone_number)
+                .then_some(updated.phone_number),
+            id_card: (updated.id_card != original_id_card).then_some(updated.id_card),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first_name.is_none()
+            && self.last_name.is_none()
+            && self.date_of_birth.is_none()
+            && self.phone_number.is_none()
+            && self.id_card.is_none()
+    }
+}
+
+pub async fn update_guest(
+    client: ApiClient,
+    guest_id: Uuid,
+    input: UpdateGuestInput,
+) -> ApiResult<()> {
+    println!("Input:{input:?}");
+    let path = UPDATE_GUEST_PATH.to_owned() + &guest_id.to_string();
+
+    client.patch_no_content(&path, &input).await
+}
@@ -0,0 +1,17 @@
+use uuid::Uuid;
+
+use crate::constants::UPDATE_ROOM_PATH;
+
+use super::{
+    add_room::{validate_input, AddRoomInput},
+    api_client::{ApiClient, ApiResult},
+};
+
+pub async fn update_room(client: ApiClient, room_id: Uuid, input: AddRoomInput) -> ApiResult<()> {
+    if let Err(err) = validate_input(&input) {
+        return ApiResult::BadRequest(err);
+    }
+    let path = UPDATE_ROOM_PATH.to_owned() + &room_id.to_string();
+
+    client.put_no_content(&path, &input).await
+}
@@ -0,0 +1,127 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Nonce,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{APP_CONFIG_DIR, SESSION_FILE, SESSION_KEY_FILE};
+
+const NONCE_LENGTH: usize = 12;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StoredSession {
+    pub email: String,
+    pub token: String,
+}
+
+fn session_dir() -> Result<PathBuf, String> {
+    dirs::config_dir()
+        .map(|dir| dir.join(APP_CONFIG_DIR))
+        .ok_or_else(|| "No config directory available".to_owned())
+}
+
+// The key is kept in the local data directory, apart from the session in the
+// config directory, so copying the config directory alone does not expose the token
+fn key_dir() -> Result<PathBuf, String> {
+    dirs::data_local_dir()
+        .map(|dir| dir.join(APP_CONFIG_DIR))
+        .ok_or_else(|| "No data directory available".to_owned())
+}
+
+fn write_private(path: &Path, contents: &[u8]) -> Result<(), String> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    options
+        .open(path)
+        .and_then(|mut file| file.write_all(contents))
+        .map_err(|err| format!("Error writing '{}': {err}", path.display()))
+}
+
+fn remove_file(path: &Path) -> Result<(), String> {
+    match fs::remove_file(path) {
+        Ok(_) => Ok(()),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(format!("Error removing '{}': {err}", path.display())),
+    }
+}
+
+fn create_dir(dir: &Path) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|err| format!("Error creating '{}': {err}", dir.display()))
+}
+
+fn load_cipher() -> Result<Aes256Gcm, String> {
+    let dir = key_dir()?;
+    let path = dir.join(SESSION_KEY_FILE);
+    if let Ok(key) = fs::read(&path) {
+        if let Ok(cipher) = Aes256Gcm::new_from_slice(&key) {
+            return Ok(cipher);
+        }
+        println!("Invalid session key, generating a new one");
+    }
+
+    create_dir(&dir)?;
+    let key = Aes256Gcm::generate_key(OsRng);
+    write_private(&path, &key)?;
+    Ok(Aes256Gcm::new(&key))
+}
+
+pub fn save_session(session: &StoredSession) -> Result<(), String> {
+    let dir = session_dir()?;
+    create_dir(&dir)?;
+
+    let cipher = load_cipher()?;
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let plaintext =
+        serde_json::to_vec(session).map_err(|err| format!("Error encoding session: {err}"))?;
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_slice())
+        .map_err(|_| "Error encrypting session".to_owned())?;
+
+    write_private(
+        &dir.join(SESSION_FILE),
+        &[nonce.as_slice(), &ciphertext].concat(),
+    )
+}
+
+pub fn load_session() -> Result<Option<StoredSession>, String> {
+    let dir = session_dir()?;
+    let contents = match fs::read(dir.join(SESSION_FILE)) {
+        Ok(ok) => ok,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(format!("Error reading stored session: {err}")),
+    };
+    let key = fs::read(key_dir()?.join(SESSION_KEY_FILE))
+        .map_err(|err| format!("Error reading session key: {err}"))?;
+
+    if contents.len() < NONCE_LENGTH {
+        return Err("Stored session is corrupted".to_owned());
+    }
+    let (nonce, ciphertext) = contents.split_at(NONCE_LENGTH);
+    let cipher =
+        Aes256Gcm::new_from_slice(&key).map_err(|_| "Session key is corrupted".to_owned())?;
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Error decrypting stored session".to_owned())?;
+
+    serde_json::from_slice(&plaintext)
+        .map(Some)
+        .map_err(|err| format!("Error decoding stored session: {err}"))
+}
+
+pub fn clear_session() -> Result<(), String> {
+    let dir = session_dir()?;
+    remove_file(&dir.join(SESSION_FILE))?;
+    remove_file(&key_dir()?.join(SESSION_KEY_FILE))
+}
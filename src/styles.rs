@@ -7,3 +7,4 @@ pub const TEXT_BOX_WIDTH: f32 = 250.0;
 pub const NAVIGATION_BUTTON_WIDTH: f32 = 180.0;
 pub const TITLE_FONT_SIZE: f32 = 20.0;
 pub const ERROR_COLOR: Color = Color::from_rgb(1.0, 0.0, 0.0);
+pub const WARNING_COLOR: Color = Color::from_rgb(1.0, 0.65, 0.0);
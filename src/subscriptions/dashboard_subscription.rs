@@ -0,0 +1,17 @@
+use std::time::Duration;
+
+use iced::{time::every, Subscription};
+
+use crate::{
+    app::{AppMessage, HotelApp, ScreenType},
+    constants::DASHBOARD_REFRESH_PERIOD,
+    screens::home::HomeMessage,
+};
+
+pub fn dashboard_subscription(hotel_app: &HotelApp) -> Subscription<AppMessage> {
+    match hotel_app.screen_type() {
+        ScreenType::Home => every(Duration::from_secs(DASHBOARD_REFRESH_PERIOD))
+            .map(|_| AppMessage::HomeMessage(HomeMessage::Refresh)),
+        _ => Subscription::none(),
+    }
+}
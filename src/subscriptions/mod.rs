@@ -1,2 +1,17 @@
+use iced::Subscription;
+
+use crate::app::{AppMessage, HotelApp};
+
+pub mod dashboard_subscription;
+pub mod navigation_subscription;
 pub mod refresh_token_subscription;
 pub mod select_text_input_subscription;
+
+pub fn subscriptions(hotel_app: &HotelApp) -> Subscription<AppMessage> {
+    Subscription::batch([
+        refresh_token_subscription::refresh_token_subscription(hotel_app),
+        select_text_input_subscription::select_text_input_subscription(hotel_app),
+        navigation_subscription::navigation_subscription(hotel_app),
+        dashboard_subscription::dashboard_subscription(hotel_app),
+    ])
+}
@@ -0,0 +1,22 @@
+use iced::{
+    event::listen_with,
+    keyboard::{key::Named, Event, Key},
+    Subscription,
+};
+
+use crate::app::{AppMessage, HotelApp};
+
+pub fn navigation_subscription(_hotel_app: &HotelApp) -> Subscription<AppMessage> {
+    listen_with(|event, _status, _window| match event {
+        iced::Event::Keyboard(Event::KeyPressed {
+            key: Key::Named(named),
+            modifiers,
+            ..
+        }) if modifiers.alt() => match named {
+            Named::ArrowLeft => Some(AppMessage::NavigateBack),
+            Named::ArrowRight => Some(AppMessage::NavigateForward),
+            _ => None,
+        },
+        _ => None,
+    })
+}
@@ -1,12 +1,43 @@
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use iced::{time::every, Subscription};
+use iced::{
+    futures::{stream, Stream, StreamExt},
+    Subscription,
+};
+use tokio::time::sleep;
 
 use crate::{
     app::{AppMessage, HotelApp},
-    constants::REFRESH_TOKEN_PERIOD,
+    constants::{REFRESH_TOKEN_MARGIN, SESSION_EXPIRY_WARNING},
 };
 
-pub fn refresh_token_subscription(_hotel_app: &HotelApp) -> Subscription<AppMessage> {
-    every(Duration::from_secs(REFRESH_TOKEN_PERIOD)).map(|_| AppMessage::RefreshToken)
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+fn session_events(iat: u64, exp: u64) -> impl Stream<Item = AppMessage> {
+    let margin = REFRESH_TOKEN_MARGIN.min(exp.saturating_sub(iat) / 2);
+    let schedule = [
+        (exp.saturating_sub(margin), AppMessage::RefreshToken),
+        // Never warn before the refresh, a successful refresh replaces this stream
+        (
+            exp.saturating_sub(margin.min(SESSION_EXPIRY_WARNING)),
+            AppMessage::SessionExpiring,
+        ),
+        (exp, AppMessage::TokenExpired),
+    ];
+
+    stream::iter(schedule).then(|(at, message)| async move {
+        sleep(Duration::from_secs(at.saturating_sub(now()))).await;
+        message
+    })
+}
+
+pub fn refresh_token_subscription(hotel_app: &HotelApp) -> Subscription<AppMessage> {
+    match hotel_app.session_expiry() {
+        Some((iat, exp)) => Subscription::run_with_id(exp, session_events(iat, exp)),
+        None => Subscription::none(),
+    }
 }
@@ -1,3 +1,4 @@
+use chrono::{Days, NaiveDate};
 use iced_aw::date_picker::Date;
 use reqwest::Response;
 use serde::Deserialize;
@@ -60,3 +61,32 @@ pub fn string_to_date(date_string: &str) -> Date {
 
     Date::from_ymd(year, month, day)
 }
+
+pub fn is_date_before(date: Date, other: Date) -> bool {
+    (date.year, date.month, date.day) < (other.year, other.month, other.day)
+}
+
+pub fn is_same_date(date: Date, other: Date) -> bool {
+    (date.year, date.month, date.day) == (other.year, other.month, other.day)
+}
+
+pub fn days_between(start: Date, end: Date) -> i64 {
+    let start: NaiveDate = start.into();
+    let end: NaiveDate = end.into();
+
+    (end - start).num_days()
+}
+
+pub fn add_days(date: Date, days: i64) -> Date {
+    let Some(naive_date) = NaiveDate::from_ymd_opt(date.year, date.month, date.day) else {
+        println!("Invalid date: '{date}'");
+        return date;
+    };
+    let result = if days >= 0 {
+        naive_date.checked_add_days(Days::new(days.unsigned_abs()))
+    } else {
+        naive_date.checked_sub_days(Days::new(days.unsigned_abs()))
+    };
+
+    result.map_or(date, Date::from)
+}